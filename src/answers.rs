@@ -0,0 +1,114 @@
+use anyhow::*;
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// Name of the file in the input directory that holds known answers, next to the inputs they
+/// belong to.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known-good answers for the real inputs, stored as one table per input (`09` for the main input,
+/// `09.alt` for a named one):
+///
+/// ```toml
+/// [05]
+/// part1 = "143"
+/// part2 = "123"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    table: Table,
+}
+
+/// How an answer compares to the recorded one.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+fn part_key(part: u32) -> String {
+    format!("part{}", part)
+}
+
+impl Answers {
+    /// Loads the answers from `path`. A missing file just means nothing has been recorded yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Result::Ok(s) => s.parse(),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e).with_context(|| format!("Couldn't read {}", path.display())),
+        }
+        .with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.table.to_string()).with_context(|| format!("Couldn't write {}", path.display()))
+    }
+
+    /// The recorded answer for one part of a day. Numbers may be written without quotes.
+    pub fn get(&self, day: &str, part: u32) -> Option<String> {
+        match self.table.get(day)?.get(part_key(part))? {
+            Value::String(s) => Some(s.clone()),
+            value => Some(value.to_string()),
+        }
+    }
+
+    pub fn record(&mut self, day: &str, part: u32, answer: &str) {
+        let day_table = self.table.entry(day)
+            .or_insert_with(|| Value::Table(Table::new()));
+        if let Value::Table(day_table) = day_table {
+            day_table.insert(part_key(part), Value::String(answer.to_string()));
+        }
+    }
+
+    pub fn verify(&self, day: &str, part: u32, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Answers { table: s.parse()? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_and_records() {
+        let mut answers: Answers = "[05]\npart1 = \"143\"\npart2 = 123\n".parse().unwrap();
+        assert_eq!(Verdict::Pass, answers.verify("05", 1, "143"));
+        assert_eq!(Verdict::Pass, answers.verify("05", 2, "123"));
+        assert_eq!(Verdict::Fail { expected: "143".to_string() }, answers.verify("05", 1, "144"));
+        assert_eq!(Verdict::Unknown, answers.verify("17", 1, "4,6,3"));
+
+        answers.record("17", 1, "4,6,3");
+        let reloaded: Answers = answers.table.to_string().parse().unwrap();
+        assert_eq!(Verdict::Pass, reloaded.verify("17", 1, "4,6,3"));
+        assert_eq!(Verdict::Pass, reloaded.verify("05", 1, "143"));
+    }
+}
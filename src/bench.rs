@@ -0,0 +1,222 @@
+use crate::input::InputLoader;
+use crate::runner::{self, RunOptions};
+use crate::solution::{Params, Runnable};
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// What `aoc bench` should run, on top of the usual day, part and input selection.
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub run: RunOptions,
+    /// Timed runs per part.
+    pub runs: usize,
+    /// Untimed runs first, to warm up caches and the allocator.
+    pub warmup: usize,
+    /// Where to save this run's results as a baseline.
+    pub save: Option<PathBuf>,
+    /// Earlier results to compare against.
+    pub baseline: Option<PathBuf>,
+    /// How much slower (in percent) a median can get before it's flagged.
+    pub threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions { run: RunOptions::default(), runs: 20, warmup: 3, save: None, baseline: None, threshold: 10.0 }
+    }
+}
+
+impl BenchOptions {
+    /// Parses the arguments following `bench`, eg. `5 12-16 --runs 50 --baseline bench.json`.
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let mut options = BenchOptions::default();
+        let mut run_args = Vec::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| anyhow!("{} needs a value", arg));
+            match arg.as_str() {
+                "--runs" => options.runs = value()?.parse().context("Invalid --runs")?,
+                "--warmup" => options.warmup = value()?.parse().context("Invalid --warmup")?,
+                "--save" => options.save = Some(value()?.into()),
+                "--baseline" => options.baseline = Some(value()?.into()),
+                "--threshold" => options.threshold = value()?.parse().context("Invalid --threshold")?,
+                _ => run_args.push(arg.clone()),
+            }
+        }
+        options.run = RunOptions::parse(&run_args)?;
+        ensure!(!options.run.record, "--record only applies to `aoc run`");
+        ensure!(options.runs > 0, "Need at least one run");
+        Ok(options)
+    }
+}
+
+/// Summary of a set of timings, in nanoseconds so that it serializes simply.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    pub fn from_timings(timings: &[Duration]) -> Self {
+        let mut sorted = timings.iter().map(|t| t.as_nanos() as u64).collect::<Vec<_>>();
+        sorted.sort_unstable();
+        // Nearest rank, so that p95 of a handful of runs is the slowest rather than made up
+        let percentile = |p: usize| sorted[(sorted.len() * p).div_ceil(100).max(1) - 1];
+        Stats { min_ns: sorted[0], median_ns: percentile(50), p95_ns: percentile(95) }
+    }
+}
+
+/// Timings for one part of one day's input.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PartBench {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Saved results, keyed by input and part, eg. `09/1` or `09.alt/2`.
+pub type Baseline = BTreeMap<String, PartBench>;
+
+/// How much slower (positive) or faster (negative) `current` is than `baseline`, in percent.
+pub fn change(baseline: u64, current: u64) -> f64 {
+    if baseline == 0 {
+        return 0.0;
+    }
+    (current as f64 - baseline as f64) / baseline as f64 * 100.0
+}
+
+fn bench_part(solution: &dyn Runnable, input: &str, part: u32, options: &BenchOptions) -> Result<PartBench> {
+    let params = Params::default();
+    for _ in 0..options.warmup {
+        solution.run(&mut input.as_bytes(), part, &params)?;
+    }
+
+    let mut parse_times = Vec::with_capacity(options.runs);
+    let mut solve_times = Vec::with_capacity(options.runs);
+    for _ in 0..options.runs {
+        let run = solution.run(&mut input.as_bytes(), part, &params)?;
+        parse_times.push(run.parse_time);
+        solve_times.push(run.solve_time);
+    }
+    Ok(PartBench { parse: Stats::from_timings(&parse_times), solve: Stats::from_timings(&solve_times) })
+}
+
+fn ns(ns: u64) -> Duration {
+    Duration::from_nanos(ns)
+}
+
+/// Benchmarks the selected days and prints their timings, compared against a baseline if there is
+/// one. Fails if anything got slower than the threshold allows.
+pub fn bench(options: &BenchOptions) -> Result<()> {
+    let loader = InputLoader::from_env()?;
+    let baseline: Baseline = match &options.baseline {
+        Some(path) => {
+            let json = fs::read_to_string(path).with_context(|| format!("Couldn't read {}", path.display()))?;
+            serde_json::from_str(&json).with_context(|| format!("Invalid baseline {}", path.display()))?
+        }
+        None => Baseline::new(),
+    };
+
+    println!("Benchmarking with {} runs after {} warm-up runs", options.runs, options.warmup);
+    println!();
+    println!("{:>3}  {:>4}  {:<5}  {:>10}  {:>10}  {:>10}  Baseline", "Day", "Part", "Phase", "Min", "Median", "p95");
+    let mut results = Baseline::new();
+    let mut regressions = Vec::new();
+    for solution in runner::select(&options.run)? {
+        let stem = InputLoader::stem(solution.day(), options.run.input.as_deref());
+        let input = match loader.read_to_string(solution.day(), options.run.input.as_deref()) {
+            Result::Ok(input) => input,
+            Err(e) => {
+                println!("{:>3}  {:>4}  error: {:#}", stem, "-", e);
+                continue;
+            }
+        };
+
+        for part in [1, 2].into_iter().filter(|&part| options.run.runs_part(part)) {
+            let key = format!("{}/{}", stem, part);
+            let result = match bench_part(solution, &input, part, options) {
+                Result::Ok(result) => result,
+                Err(e) => {
+                    println!("{:>3}  {:>4}  error: {:#}", stem, part, e);
+                    continue;
+                }
+            };
+
+            let before = baseline.get(&key);
+            for (phase, stats, old) in [
+                ("parse", result.parse, before.map(|b| b.parse)),
+                ("solve", result.solve, before.map(|b| b.solve)),
+            ] {
+                let comparison = match old {
+                    Some(old) => {
+                        let change = change(old.median_ns, stats.median_ns);
+                        if change > options.threshold {
+                            regressions.push(format!("{} {}", key, phase));
+                            format!("{:+.1}% REGRESSION", change)
+                        } else {
+                            format!("{:+.1}%", change)
+                        }
+                    }
+                    None => String::new(),
+                };
+                let row = format!(
+                    "{:>3}  {:>4}  {:<5}  {:>10.2?}  {:>10.2?}  {:>10.2?}  {}",
+                    stem, part, phase, ns(stats.min_ns), ns(stats.median_ns), ns(stats.p95_ns), comparison,
+                );
+                println!("{}", row.trim_end());
+            }
+            results.insert(key, result);
+        }
+    }
+
+    if let Some(path) = &options.save {
+        fs::write(path, serde_json::to_string_pretty(&results)?)
+            .with_context(|| format!("Couldn't write {}", path.display()))?;
+        println!("Saved baseline to {}", path.display());
+    }
+    ensure!(
+        regressions.is_empty(),
+        "{} median(s) more than {}% slower than the baseline: {}",
+        regressions.len(), options.threshold, regressions.join(", "),
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_timings() {
+        let timings = (1..=20).rev().map(Duration::from_micros).collect::<Vec<_>>();
+        let stats = Stats::from_timings(&timings);
+        assert_eq!(Stats { min_ns: 1_000, median_ns: 10_000, p95_ns: 19_000 }, stats);
+        assert_eq!(Stats { min_ns: 7, median_ns: 7, p95_ns: 7 }, Stats::from_timings(&[Duration::from_nanos(7)]));
+
+        assert_eq!(50.0, change(100, 150));
+        assert_eq!(-25.0, change(100, 75));
+    }
+
+    #[test]
+    fn parses_bench_options() {
+        let args = ["9", "--runs", "5", "--baseline", "old.json", "--part", "1"].map(String::from);
+        let options = BenchOptions::parse(&args).unwrap();
+        assert_eq!((vec![9], Some(1)), (options.run.days.clone(), options.run.part));
+        assert_eq!((5, Some(PathBuf::from("old.json"))), (options.runs, options.baseline));
+        assert!(BenchOptions::parse(&["--record".to_string()]).is_err());
+        assert!(BenchOptions::parse(&["--runs".to_string()]).is_err());
+    }
+
+    #[test]
+    fn baseline_round_trips() {
+        let stats = Stats { min_ns: 1, median_ns: 2, p95_ns: 3 };
+        let baseline = Baseline::from([("09/1".to_string(), PartBench { parse: stats, solve: stats })]);
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(baseline, serde_json::from_str::<Baseline>(&json).unwrap());
+    }
+}
@@ -0,0 +1,324 @@
+use adv_code_2024::bench::{self, BenchOptions};
+use adv_code_2024::days::day14::{self, arena::RobotArena};
+use adv_code_2024::days::day15::{self, interactive::{self, Session}, warehouse::{self, Warehouse}};
+use adv_code_2024::days::day16::{self, Maze};
+use adv_code_2024::days::day17::{self, quine::{self, Strategy}};
+use adv_code_2024::days::day18::{self, timeline};
+use adv_code_2024::input::InputLoader;
+use adv_code_2024::maze::{self, MazeOptions};
+use adv_code_2024::runner::{self, RunOptions};
+use adv_code_2024::scaffold::{self, Example};
+use adv_code_2024::solution::{Params, Solution};
+use std::time::Duration;
+use anyhow::*;
+
+const USAGE: &str = "\
+Usage: aoc run [DAYS...] [--part N] [--input NAME] [--record]
+       aoc bench [DAYS...] [--part N] [--input NAME] [--runs N] [--warmup N]
+                 [--save FILE] [--baseline FILE] [--threshold PCT]
+       aoc new DAY [--example FILE]
+       aoc robots [--input NAME] [--size WIDTHxHEIGHT] [--csv FILE] [--seconds N]
+       aoc warehouse [--wide] [--input NAME] [--save FILE]
+       aoc warehouse [--wide] [--input NAME] [--animation FILE] [--frames DIR] [--scale N]
+       aoc reindeer [--input NAME] [--turn-cost N] [--paths N]
+       aoc maze WIDTHxHEIGHT [--loops FRACTION] [--seed N]
+       aoc quines [--input NAME]
+       aoc bytes [COUNTS] [--input NAME] [--size N] [--delay MS] [--frames DIR] [--scale N]
+       aoc bytes [COUNTS] [--input NAME] [--size N] --lengths
+
+  DAYS            days to run, eg. `5` or `12-16` (default: all of them)
+  --part N        only run part 1 or part 2
+  --input NAME    run against NN.NAME.txt instead of NN.txt
+  --record        save answers that aren't in answers.toml yet
+  --runs N        timed runs per part (default 20), after --warmup untimed ones (default 3)
+  --save FILE     save the timings as a JSON baseline
+  --baseline FILE compare against a saved baseline, failing if a median is more than
+                  --threshold percent slower (default 10)
+  --example FILE  example input for the new day (`-` for stdin), optionally followed by a `---`
+                  line and the expected answers as `part1 = 11` and `part2 = 31`
+
+`aoc robots` shows the day 14 robots when they make the tree, and how bunched up they are.
+  --size WIDTHxHEIGHT size of the room (default 101x103)
+  --csv FILE      also write where every robot is from --seconds (default 10) before the tree to as
+                  many after, as `time,robot,x,y` rows
+
+`aoc warehouse` drives the day 15 robot with the arrow keys, starting from the input's map.
+  --wide          use the part 2 warehouse
+  --save FILE     where `s` saves the moves so far as a puzzle input (default: 15.played.txt
+                  next to the inputs, so `aoc run 15 --input played` runs them)
+  --animation FILE instead of playing, make the input's moves and write every frame to FILE as text
+  --frames DIR    or as PPM images in DIR, --scale pixels per tile (default 4), with the tiles that
+                  changed highlighted
+
+`aoc reindeer` shows the tiles on the best day 16 paths and how many of those paths there are.
+  --turn-cost N   score for turning (default 1000)
+  --paths N       also list up to N of the paths, as F for forward and L and R for turns
+
+`aoc maze` prints a random maze with S and E, to save as an input for days 16 and 20.
+  --loops FRACTION how many walls between corridors to knock through, 0 to 1 (default 0.1)
+  --seed N         the same seed always makes the same maze (default 0)
+
+`aoc quines` lists every value of register A that makes the day 17 program output itself, where
+part 2 only gives the smallest.
+
+`aoc bytes` shows the day 18 bytes falling and the shortest path around them, up to the byte that
+cuts it off.
+  COUNTS          how many bytes have fallen, `1024` for one frame or `1024-3000` for an animation
+                  (default: from 1024 until the way is cut off)
+  --size N        size of the memory space (default 71)
+  --delay MS      time between frames in the terminal (default 50)
+  --frames DIR    write the frames to DIR as PPM images instead, --scale pixels per cell (default 8)
+  --lengths       list the shortest path's length after each byte instead, one byte per line
+
+Inputs are read from $AOC_INPUT_DIR, the `input_dir` set in aoc.toml, or ./input, in that order.
+";
+
+fn new_day(args: &[String]) -> Result<()> {
+    let mut day = None;
+    let mut example = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--example" {
+            let path = args.next().ok_or(Error::msg("--example needs a file"))?;
+            example = Some(Example::read(path)?);
+        } else {
+            ensure!(day.is_none(), "Unexpected argument '{}'", arg);
+            day = Some(arg.parse().with_context(|| format!("Invalid day '{}'", arg))?);
+        }
+    }
+    let day = day.ok_or(Error::msg("Which day?"))?;
+    scaffold::new_day(day, example.as_ref())
+}
+
+// A size like `41x41`
+fn parse_size(arg: &str) -> Result<(usize, usize)> {
+    let (width, height) = arg.split_once('x').ok_or_else(|| Error::msg(format!("Expected a size like 41x41, not '{}'", arg)))?;
+    Ok((
+        width.parse().with_context(|| format!("Invalid width '{}'", width))?,
+        height.parse().with_context(|| format!("Invalid height '{}'", height))?,
+    ))
+}
+
+fn robots(args: &[String]) -> Result<()> {
+    let mut params = Params::default();
+    let mut input = None;
+    let mut csv = None;
+    let mut seconds = 10;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = Some(args.next().ok_or(Error::msg("--input needs a name"))?.as_str()),
+            "--size" => (params.width, params.height) = parse_size(args.next().ok_or(Error::msg("--size needs a size"))?)?,
+            "--csv" => csv = Some(args.next().ok_or(Error::msg("--csv needs a file"))?.as_str()),
+            "--seconds" => {
+                let value = args.next().ok_or(Error::msg("--seconds needs a number"))?;
+                seconds = value.parse::<u32>().with_context(|| format!("Invalid number '{}'", value))? as i64;
+            }
+            _ => bail!("Unexpected argument '{}'", arg),
+        }
+    }
+    let loader = InputLoader::from_env()?;
+    let robots = day14::Day14::parse(loader.open(day14::DAY, input)?)?;
+    let arena = RobotArena::new(params.width, params.height, &robots)?;
+    let time = day14::Day14::part2(&robots, &params)? as i64;
+    let clustering = arena.clustering_at(time);
+    print!("{}", arena.render(time));
+    println!("{} seconds in, {} robots are next to another and the longest row of them is {}",
+             time, clustering.with_neighbors, clustering.longest_run);
+    if let Some(csv) = csv {
+        arena.write_csv(csv, time - seconds..time + seconds + 1)?;
+        println!("Wrote seconds {} to {} to {}", time - seconds, time + seconds, csv);
+    }
+    Ok(())
+}
+
+fn warehouse(args: &[String]) -> Result<()> {
+    let mut wide = false;
+    let mut input = None;
+    let mut save_to = None;
+    let mut animation = None;
+    let mut dir = None;
+    let mut scale = 4;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--wide" => wide = true,
+            "--input" => input = Some(args.next().ok_or(Error::msg("--input needs a name"))?.as_str()),
+            "--save" => save_to = Some(args.next().ok_or(Error::msg("--save needs a file"))?.into()),
+            "--animation" => animation = Some(args.next().ok_or(Error::msg("--animation needs a file"))?.as_str()),
+            "--frames" => dir = Some(args.next().ok_or(Error::msg("--frames needs a directory"))?.as_str()),
+            "--scale" => {
+                let value = args.next().ok_or(Error::msg("--scale needs a number"))?;
+                scale = value.parse().with_context(|| format!("Invalid number '{}'", value))?;
+            }
+            _ => bail!("Unexpected argument '{}'", arg),
+        }
+    }
+    let loader = InputLoader::from_env()?;
+    let (map, moves) = day15::Day15::parse(loader.open(day15::DAY, input)?)?;
+    if animation.is_some() || dir.is_some() {
+        ensure!(save_to.is_none(), "--save is for playing, not for --animation or --frames");
+        let mut warehouse = Warehouse::new(if wide { warehouse::widen(&map)? } else { map })?;
+        warehouse.run(&moves);
+        if let Some(animation) = animation {
+            warehouse.write_animation(animation)?;
+        }
+        if let Some(dir) = dir {
+            warehouse.write_frames(dir, scale)?;
+        }
+        return Ok(());
+    }
+    let save_to = save_to.unwrap_or_else(|| loader.path(day15::DAY, Some("played")));
+    interactive::play(&mut Session::new(map, wide, save_to)?)
+}
+
+fn reindeer(args: &[String]) -> Result<()> {
+    let mut params = Params::default();
+    let mut input = None;
+    let mut limit = 0;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut number = |name: &str| -> Result<usize> {
+            let value = args.next().ok_or_else(|| Error::msg(format!("{} needs a number", name)))?;
+            value.parse().with_context(|| format!("Invalid number '{}'", value))
+        };
+        match arg.as_str() {
+            "--input" => input = Some(args.next().ok_or(Error::msg("--input needs a name"))?.as_str()),
+            "--turn-cost" => params.turn_cost = number("--turn-cost")?,
+            "--paths" => limit = number("--paths")?,
+            _ => bail!("Unexpected argument '{}'", arg),
+        }
+    }
+    let loader = InputLoader::from_env()?;
+    let map = day16::Day16::parse(loader.open(day16::DAY, input)?)?;
+    let best = Maze::new(&map, params.turn_cost)?.solve().ok_or(Error::msg("No path found"))?;
+    print!("{}", best.overlay(&map));
+    println!("{} best paths scoring {}, over {} tiles", best.count(), best.cost(), best.tiles().len());
+    for path in best.paths(limit) {
+        println!("{}", path.iter().map(ToString::to_string).collect::<String>());
+    }
+    Ok(())
+}
+
+fn generate_maze(args: &[String]) -> Result<()> {
+    let mut options = MazeOptions::default();
+    let mut size = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--loops" => {
+                let loops = args.next().ok_or(Error::msg("--loops needs a fraction"))?;
+                options.loops = loops.parse().with_context(|| format!("Invalid fraction '{}'", loops))?;
+            }
+            "--seed" => {
+                let seed = args.next().ok_or(Error::msg("--seed needs a number"))?;
+                options.seed = seed.parse().with_context(|| format!("Invalid seed '{}'", seed))?;
+            }
+            _ => {
+                ensure!(size.is_none(), "Unexpected argument '{}'", arg);
+                size = Some(parse_size(arg)?);
+            }
+        }
+    }
+    (options.width, options.height) = size.ok_or(Error::msg("How big?"))?;
+    print!("{}", maze::generate(&options)?);
+    Ok(())
+}
+
+fn quines(args: &[String]) -> Result<()> {
+    let mut input = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = Some(args.next().ok_or(Error::msg("--input needs a name"))?.as_str()),
+            _ => bail!("Unexpected argument '{}'", arg),
+        }
+    }
+    let loader = InputLoader::from_env()?;
+    let computer = day17::Day17::parse(loader.open(day17::DAY, input)?)?;
+    let quines = quine::find_quines(&computer, quine::BRUTE_FORCE_LIMIT)?;
+    match quines.strategy {
+        Strategy::Reverse { shift } => println!("{} values, building A {} bits at a time:", quines.values.len(), shift),
+        Strategy::BruteForce { limit } => println!("{} values, trying every A below {}:", quines.values.len(), limit),
+    }
+    for value in &quines.values {
+        println!("{}", value);
+    }
+    Ok(())
+}
+
+fn falling_bytes(args: &[String]) -> Result<()> {
+    let params = Params::default();
+    let mut counts = None;
+    let mut input = None;
+    let mut size = params.size;
+    let mut delay = 50;
+    let mut dir = None;
+    let mut scale = 8;
+    let mut lengths = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut number = |name: &str| -> Result<usize> {
+            let value = args.next().ok_or_else(|| Error::msg(format!("{} needs a number", name)))?;
+            value.parse().with_context(|| format!("Invalid number '{}'", value))
+        };
+        match arg.as_str() {
+            "--input" => input = Some(args.next().ok_or(Error::msg("--input needs a name"))?.as_str()),
+            "--size" => size = number("--size")?,
+            "--delay" => delay = number("--delay")?,
+            "--scale" => scale = number("--scale")?,
+            "--frames" => dir = Some(args.next().ok_or(Error::msg("--frames needs a directory"))?.as_str()),
+            "--lengths" => lengths = true,
+            _ => {
+                ensure!(counts.is_none(), "Unexpected argument '{}'", arg);
+                counts = Some(timeline::parse_counts(arg)?);
+            }
+        }
+    }
+
+    let loader = InputLoader::from_env()?;
+    let bytes = day18::Day18::parse(loader.open(day18::DAY, input)?)?;
+    let counts = counts.unwrap_or(params.num_fallen.min(bytes.len())..bytes.len() + 1);
+    if lengths {
+        // The length after byte i falls is for i + 1 fallen bytes
+        for (i, length) in day18::path_lengths(&bytes, size)?.into_iter().enumerate() {
+            if counts.contains(&(i + 1)) {
+                match length {
+                    Some(length) => println!("{} {} {}", i + 1, bytes[i], length),
+                    None => {
+                        println!("{} {} cut off", i + 1, bytes[i]);
+                        break;
+                    }
+                }
+            }
+        }
+        return Ok(());
+    }
+    let mut frames = timeline::frames(&bytes, size, counts)?;
+    if let Some(cut_off) = frames.iter().position(timeline::Frame::is_cut_off) {
+        frames.truncate(cut_off + 1);
+    }
+    match dir {
+        Some(dir) => timeline::write_frames(&frames, dir, scale),
+        None => timeline::play(&frames, Duration::from_millis(delay as u64)),
+    }
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("run") => runner::run(&RunOptions::parse(&args[1..])?),
+        Some("bench") => bench::bench(&BenchOptions::parse(&args[1..])?),
+        Some("new") => new_day(&args[1..]),
+        Some("robots") => robots(&args[1..]),
+        Some("warehouse") => warehouse(&args[1..]),
+        Some("reindeer") => reindeer(&args[1..]),
+        Some("maze") => generate_maze(&args[1..]),
+        Some("quines") => quines(&args[1..]),
+        Some("bytes") => falling_bytes(&args[1..]),
+        _ => {
+            eprint!("{}", USAGE);
+            bail!("Expected a command");
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use anyhow::*;
+use itertools::Itertools;
+use std::io::BufRead;
+use std::iter::zip;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "01";
+
+const TEST: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: &'static str = DAY;
+    type Input = Vec<(u32, u32)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse(reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<u32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<u32> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day01;
+    part1_test: part1(TEST) == 11;
+    part2_test: part2(TEST) == 31;
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<Vec<(u32, u32)>> {
+    let input = parse::read_all(reader)?;
+    parse::lines(DAY, &input)
+        .map(|line| extract!(line, "{} {}" => u32, u32))
+        .collect()
+}
+
+//region Part 1
+fn part1(input: &[(u32, u32)]) -> Result<u32> {
+    let arr1 = input.iter()
+        .map(|(a, _)| a)
+        .sorted()
+        .collect::<Vec<&u32>>();
+    let arr2 = input.iter()
+        .map(|(_, b)| b)
+        .sorted()
+        .collect::<Vec<&u32>>();
+    let answer = zip(arr1, arr2)
+        .map(|(a, b)| a.abs_diff(*b))
+        .sum::<u32>();
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+fn part2(input: &[(u32, u32)]) -> Result<u32> {
+    // Count number of occurences of each number in the second column
+    let arr2_counts = input.iter()
+        .map(|(_, b)| b)
+        .fold(HashMap::new(), |mut counts, val| {
+            counts.entry(val)
+                .and_modify(|v| *v += 1)
+                .or_insert(1u32);
+            counts
+        });
+
+    // For each number in the first column, multiply by its count in the second column then sum
+    let answer = input.iter()
+        .map(|(a, _)| a)
+        .map(|a| a * arr2_counts.get(a).unwrap_or(&0u32))
+        .sum::<u32>();
+    Ok(answer)
+}
+//endregion
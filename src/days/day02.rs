@@ -0,0 +1,88 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "02";
+
+const TEST: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: &'static str = DAY;
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse(reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<u32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<u32> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day02;
+    part1_test: part1(TEST) == 2;
+    part2_test: part2(TEST) == 4;
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<Vec<Vec<u32>>> {
+    let input = parse::read_all(reader)?;
+    parse::lines(DAY, &input)
+        .map(|line| line.list(" "))
+        .collect()
+}
+
+//region Part 1
+fn is_safe(input: &[u32]) -> bool {
+    let mut deltas: Vec<i32> = Vec::new();
+    for (index, num) in input.iter().enumerate() {
+        if index == 0 {
+            continue;
+        }
+        deltas.push((*num as i32) - (input[index - 1] as i32));
+    }
+
+    deltas.iter().all(|x| *x < 0 && *x >= -3)
+        || deltas.iter().all(|x| *x > 0 && *x <= 3)
+}
+
+fn part1(input: &[Vec<u32>]) -> Result<u32> {
+    let num_safe = input.iter().filter(|x| is_safe(x)).count() as u32;
+    Ok(num_safe)
+}
+//endregion
+
+//region Part 2
+fn part2(input: &[Vec<u32>]) -> Result<u32> {
+    let num_safe = input.iter()
+        .filter(|x| {
+            // We're now allowed to remove one item from the input - brute force try removing
+            // each item and see if any of the resulting inputs are now "safe"
+            for i in 0..x.len() {
+                let sliced_input = [&x[0..i], &x[i + 1..]].concat();
+                if is_safe(&sliced_input) {
+                    return true;
+                }
+            }
+            false
+        })
+        .count() as u32;
+    Ok(num_safe)
+}
+//endregion
@@ -0,0 +1,77 @@
+use anyhow::*;
+use std::io::BufRead;
+use regex::Regex;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "03";
+
+const TEST: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+const TEST2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: &'static str = DAY;
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self::Input> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<u64> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day03;
+    part1_test: part1(TEST) == 161;
+    part2_test2: part2(TEST2) == 48;
+}
+
+//region Part 1
+fn part1(input: &str) -> Result<u64> {
+    let mut result = 0u64;
+    let regex = Regex::new(r"mul\((\d+),(\d+)\)")?;
+    for (_, [a, b]) in regex.captures_iter(input).map(|c| c.extract()) {
+        result += a.parse::<u64>()? * b.parse::<u64>()?;
+    }
+
+    Ok(result)
+}
+//endregion
+
+//region Part 2
+fn part2(input: &str) -> Result<u64> {
+    let instruction_regex = Regex::new(r"(mul\(\d+,\d+\))|(do\(\))|(don't\(\))")?;
+    let mul_regex = Regex::new(r"mul\((\d+),(\d+)\)")?;
+
+    let mut result = 0u64;
+    let mut mul_enabled = true;
+    for (_, [instruction]) in instruction_regex.captures_iter(input).map(|c| c.extract()) {
+        if instruction == "do()" {
+            mul_enabled = true;
+        } else if instruction == "don't()" {
+            mul_enabled = false;
+        } else if instruction.starts_with("mul") && mul_enabled {
+            let (_, [a, b]) = mul_regex.captures(instruction).unwrap().extract();
+            result += a.parse::<u64>()? * b.parse::<u64>()?;
+        }
+    }
+
+    Ok(result)
+}
+//endregion
@@ -0,0 +1,92 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "04";
+
+const TEST: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: &'static str = DAY;
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse(DAY, reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day04;
+    part1_test: part1(TEST) == 18;
+    part2_test: part2(TEST) == 9;
+}
+
+//region Part 1
+fn part1(input: &Grid<u8>) -> Result<usize> {
+    let mut answer = 0;
+    // For each position in the matrix
+    for pos in input.positions() {
+        // For each direction
+        for dir in Point::NEIGHBORS8 {
+            // For each character in XMAS
+            let found = (0..4).all(|c| {
+                input.get(pos + dir * c) == Some(&b"XMAS"[c as usize])
+            });
+            if found {
+                answer += 1;
+            }
+        }
+    }
+
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+fn part2(input: &Grid<u8>) -> Result<usize> {
+    let width = input.width() as i32;
+    let height = input.height() as i32;
+
+    let mut answer = 0;
+    // For each position in the matrix
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            let c = input[Point::new(x, y)];
+            // Top left, top right, bottom right, bottom left
+            let c_tl = input[Point::new(x - 1, y - 1)];
+            let c_tr = input[Point::new(x + 1, y - 1)];
+            let c_br = input[Point::new(x + 1, y + 1)];
+            let c_bl = input[Point::new(x - 1, y + 1)];
+
+            if c == b'A' && ((c_tl == b'M' && c_br == b'S') || (c_tl == b'S' && c_br == b'M')) && ((c_tr == b'M' && c_bl == b'S') || (c_tr == b'S' && c_bl == b'M')) {
+                answer += 1;
+            }
+        }
+    }
+    Ok(answer)
+}
+//endregion
@@ -0,0 +1,174 @@
+use anyhow::*;
+use itertools::Itertools;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "05";
+
+const TEST: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: &'static str = DAY;
+    type Input = PrintQueue;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse(reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day05;
+    part1_test: part1(TEST) == 143;
+    part2_test: part2(TEST) == 123;
+}
+
+/// Ordering rules, then the updates to check against them
+type PrintQueue = (Vec<[usize; 2]>, Vec<Vec<usize>>);
+
+fn parse<R: BufRead>(reader: R) -> Result<PrintQueue> {
+    let input = parse::read_all(reader)?;
+    let [rules, updates] = parse::exact_sections(DAY, &input)?;
+    let rules = rules.iter()
+        .map(|line| extract!(line, "{}|{}" => usize, usize).map(|(before, after)| [before, after]))
+        .collect::<Result<Vec<[usize; 2]>>>()?;
+    let updates = updates.iter()
+        .map(|line| line.list(","))
+        .collect::<Result<Vec<Vec<usize>>>>()?;
+    Ok((rules, updates))
+}
+
+//region Part 1
+fn part1((rules, updates): &PrintQueue) -> Result<usize> {
+    let answer = updates
+        .iter()
+        .filter_map(|input| {
+            if rules.iter().all(|rule| {
+                let pos1 = input.iter().find_position(|it| rule[0] == **it);
+                let pos2 = input.iter().find_position(|it| rule[1] == **it);
+                match (pos1, pos2) {
+                    (Some(pos1), Some(pos2)) => {
+                        pos1 < pos2
+                    }
+                    _ => {
+                        true
+                    },
+                }
+            }) {
+                Some(input[input.len() / 2])
+            } else {
+                None
+            }
+        })
+        .sum();
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+fn part2((rules, updates): &PrintQueue) -> Result<usize> {
+    let answer = updates
+        .iter()
+        // Same as part 1
+        .filter(|input| {
+            if rules.iter().all(|rule| {
+                let pos1 = input.iter().find_position(|it| rule[0] == **it);
+                let pos2 = input.iter().find_position(|it| rule[1] == **it);
+                match (pos1, pos2) {
+                    (Some(pos1), Some(pos2)) => {
+                        pos1 < pos2
+                    }
+                    _ => {
+                        true
+                    },
+                }
+            }) {
+                false
+            } else {
+                // Keep only the unsorted ones
+                true
+            }
+        })
+        .map(|input| {
+            // Kahn's algorithm (topsort)
+            // The graph is the list of edges that are relevant to our input
+            let mut graph = rules.iter()
+                .filter(|rule| input.contains(&rule[0]) && input.contains(&rule[1]))
+                .collect::<Vec<_>>();
+            let mut result: Vec<usize> = Vec::new();
+            // Set of nodes without incoming vertices
+            let mut s: Vec<usize> = input.iter().copied()
+                .filter(|i| graph.iter().all(|rule| rule[1] != *i))
+                .collect();
+
+            while let Some(n) = s.pop() {
+                
+                result.push(n);
+                // M's to check later (an edge e goes from n to m)
+                let mut ms: Vec<usize> = Vec::new();
+                graph.retain(|edge| {
+                    if edge[0] == n {
+                        let m = edge[1];
+                        ms.push(m);
+                        // Delete all edges that originate from our popped node n
+                        false
+                    } else {
+                        true
+                    }
+                });
+
+                ms.iter().copied()
+                    .filter(|m| graph.iter().all(|edge| edge[1] != *m))
+                    .for_each(|m| s.push(m));
+            }
+
+            // println!("{} unsorted, sorted into {}", input.iter().join(","), result.iter().join(","));
+
+            result[result.len() / 2]
+        })
+        .sum();
+
+    Ok(answer)
+}
+//endregion
@@ -0,0 +1,144 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "06";
+
+const TEST: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: &'static str = DAY;
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse(DAY, reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day06;
+    part1_test: part1(TEST) == 41;
+    part2_test: part2(TEST) == 6;
+}
+
+//region Part 1
+fn part1(input: &Grid<u8>) -> Result<usize> {
+    let mut input = input.clone();
+
+    let mut dir = Direction::Up;
+    // Find the initial position
+    let mut pos = input.find(&b'^').ok_or(Error::msg("No guard found"))?;
+
+    // Start walking the guard
+    loop {
+        // println!("At pos {}", pos);
+        // Mark current position as walked
+        input[pos] = b'X';
+
+        // Move and mark new position as walked
+        let next_pos = pos.step(dir);
+
+        match input.get(next_pos) {
+            // We're about to exit
+            None => break,
+            Some(b'#') => {
+                // Turn right
+                // println!("Turn right");
+                dir = dir.turn_right();
+            }
+            Some(_) => pos = next_pos,
+        }
+    }
+    // println!("Exited at {}", pos);
+
+    // Count walked tiles
+    let num_walked = input.find_all(&b'X').count();
+
+    Ok(num_walked)
+}
+//endregion
+
+//region Part 2
+fn does_loop(input: &Grid<u8>, initial_pos: Point) -> bool {
+    // Stores whether we've been at this position facing each direction
+    let mut seen = Grid::new(input.width(), input.height(), [false; 4]);
+
+    let mut pos = initial_pos;
+    let mut dir = Direction::Up;
+
+    // Start walking the guard
+    loop {
+        // println!("At pos {} facing {}", pos, dir);
+
+        if seen[pos][dir.index()] {
+            // We've already been here, facing this direction...it's a loop!
+            return true;
+        }
+        // Mark current position as walked
+        seen[pos][dir.index()] = true;
+
+        // Try move
+        let next_pos = pos.step(dir);
+
+        match input.get(next_pos) {
+            // We're about to exit
+            None => return false,
+            Some(b'#') => {
+                // Turn right
+                // println!("Turn right");
+                dir = dir.turn_right();
+            }
+            Some(_) => pos = next_pos,
+        }
+    }
+}
+
+fn part2(input: &Grid<u8>) -> Result<usize> {
+    let mut input = input.clone();
+
+    // Find the initial position
+    let initial_pos = input.find(&b'^').ok_or(Error::msg("No guard found"))?;
+
+    // Brute-force: try placing an obstacle at every possible spot and check if it loops
+    let mut num_loops = 0;
+    for pos in input.positions() {
+        if input[pos] != b'#' && input[pos] != b'^' {
+            // println!("Trying an obstacle at {}", pos);
+            // Try turning it into an obstacle
+            input[pos] = b'#';
+            if does_loop(&input, initial_pos) {
+                // println!("{} loops!", pos);
+                num_loops += 1;
+            }
+            // Remove the obstacle for the next test
+            input[pos] = b'.';
+        }
+    }
+
+    Ok(num_loops)
+}
+//endregion
@@ -0,0 +1,152 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "07";
+
+const TEST: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+const TEST2: &str = "\
+111: 1 1 1
+";
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: &'static str = DAY;
+    /// Each equation's result, with the values that should make it
+    type Input = Vec<(usize, Vec<usize>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse(reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day07;
+    part1_test: part1(TEST) == 3749;
+    part2_test: part2(TEST) == 11387;
+    part2_test2: part2(TEST2) == 111;
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<Vec<(usize, Vec<usize>)>> {
+    let input = parse::read_all(reader)?;
+    parse::lines(DAY, &input)
+        .map(|line| {
+            // {result}: {values...}
+            let [result, values] = line.fields("{}: {}")?;
+            Ok((line.parse_field(result)?, line.parse_list(values, " ")?))
+        })
+        .collect()
+}
+
+//region Part 1
+fn can_make(result: usize, values: &[usize], index: i32) -> bool {
+    if result == 0 {
+        return true;
+    }
+    if index < 0 {
+        return false;
+    }
+    let cur = values[index as usize];
+    (cur <= result && can_make(result - cur, values, index - 1)) || (result.is_multiple_of(cur) && can_make(result / cur, values, index - 1))
+}
+
+fn part1(equations: &[(usize, Vec<usize>)]) -> Result<usize> {
+    let answer = equations.iter()
+        .filter_map(|(result, values)| {
+            // println!("Trying to make {} from {}", result, values.iter().join(","));
+
+            if can_make(*result, values, values.len() as i32 - 1) {
+                Some(result)
+            } else {
+                None
+            }
+        })
+        .sum();
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+// Given 6, return 10. Given 76, return 100
+fn next_base_10(num: usize) -> usize {
+    let mut result = 1;
+    while result <= num {
+        result *= 10;
+    }
+    result
+}
+
+fn can_make2(result: usize, values: &[usize], index: i32) -> bool {
+    // let indent = vec![" "; (values.len() as i32 - index) as usize].join("");
+    if result == 0 {
+        // println!("{}Success", indent);
+        return true;
+    }
+    if index < 0 {
+        // println!("{}Failed", indent);
+        return false;
+    }
+    // println!("{}Make {} from {}?", indent, result, values.iter().take((index + 1) as usize).join(","));
+    let cur = values[index as usize];
+    if cur <= result {
+        // println!("{}Try +", indent);
+        if can_make2(result - cur, values, index - 1) {
+            return true;
+        }
+    }
+    // Bit convoluted, but the idea is that if we need to make 486 and we're currently 6, check that
+    // we can make 48 with the remaining numbers (and that 6 matches the end digits of course)
+    let base_if_concat = next_base_10(cur);
+    // println!("{}Base is {} and cur is {} and result is {}", indent, base_if_concat, cur, result);
+    if base_if_concat < result && result % base_if_concat == cur {
+        // println!("{}Try ||", indent);
+        if can_make2(result / base_if_concat, values, index - 1) {
+            return true;
+        }
+    }
+    if result.is_multiple_of(cur) {
+        // println!("{}Try *", indent);
+        if can_make2(result / cur, values, index - 1) {
+            return true;
+        }
+    }
+    false
+}
+
+fn part2(equations: &[(usize, Vec<usize>)]) -> Result<usize> {
+    let answer = equations.iter()
+        .filter_map(|(result, values)| {
+            // println!("Trying to make {} from {}", result, values.iter().join(","));
+
+            if can_make2(*result, values, values.len() as i32 - 1) {
+                Some(result)
+            } else {
+                None
+            }
+        })
+        .sum();
+    Ok(answer)
+}
+//endregion
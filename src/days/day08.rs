@@ -0,0 +1,127 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "08";
+
+const TEST: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: &'static str = DAY;
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse(DAY, reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day08;
+    part1_test: part1(TEST) == 14;
+    part2_test: part2(TEST) == 34;
+}
+
+//region Part 1
+// Debugging
+#[allow(dead_code)]
+fn print_state(input: &Grid<u8>, antinodes: &Grid<bool>) {
+    let mut state = input.clone();
+    for pos in antinodes.find_all(&true) {
+        state[pos] = b'#';
+    }
+    println!("{}", state);
+}
+
+fn part1(input: &Grid<u8>) -> Result<usize> {
+
+    let mut antinodes = Grid::new(input.width(), input.height(), false);
+    for (pos1, &frequency) in input.iter() {
+        if frequency == b'.' {
+            continue;
+        }
+        for pos2 in input.find_all(&frequency) {
+            if pos1 == pos2 {
+                continue;
+            }
+
+            // println!("Pairing {} ({}) with ({})", char::from(frequency), pos1, pos2);
+            let delta = pos2 - pos1;
+
+            if let Some(antinode) = antinodes.get_mut(pos2 + delta) {
+                *antinode = true;
+            }
+            if let Some(antinode) = antinodes.get_mut(pos1 - delta) {
+                *antinode = true;
+            }
+            // print_state(&input, &antinodes);
+        }
+    }
+    // print_state(&input, &antinodes);
+
+    let answer = antinodes.find_all(&true).count();
+
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+fn part2(input: &Grid<u8>) -> Result<usize> {
+
+    let mut antinodes = Grid::new(input.width(), input.height(), false);
+    for (pos1, &frequency) in input.iter() {
+        if frequency == b'.' {
+            continue;
+        }
+        for pos2 in input.find_all(&frequency) {
+            if pos1 == pos2 {
+                continue;
+            }
+
+            // println!("Pairing {} ({}) with ({})", char::from(frequency), pos1, pos2);
+            let delta = pos2 - pos1;
+
+            let mut pos = pos1;
+            while let Some(antinode) = antinodes.get_mut(pos) {
+                *antinode = true;
+                pos += delta;
+            }
+            while let Some(antinode) = antinodes.get_mut(pos) {
+                *antinode = true;
+                pos -= delta;
+            }
+            // print_state(&input, &antinodes);
+        }
+    }
+    // print_state(&input, &antinodes);
+
+    let answer = antinodes.find_all(&true).count();
+
+    Ok(answer)
+}
+//endregion
@@ -0,0 +1,178 @@
+use anyhow::*;
+use std::io::BufRead;
+use itertools::Itertools;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "09";
+
+const TEST: &str = "\
+2333133121414131402
+";
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: &'static str = DAY;
+    /// The disk map, as alternating file and free space lengths
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = parse::read_all(reader)?;
+        let line = parse::lines(DAY, &input).next().ok_or(Error::msg("The disk map is empty"))?;
+        line.text.trim_end().char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .map(|d| d as usize)
+                    .ok_or_else(|| line.error_at(i, format!("Expected a digit, got '{}'", c)))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day09;
+    part1_test: part1(TEST) == 1928;
+    part2_test: part2(TEST) == 2858;
+}
+
+//region Part 1
+fn gauss_sum(start: usize, len: usize) -> usize {
+    let end = start + len - 1;
+    ((start + end) * len) / 2
+}
+
+fn part1(input: &[usize]) -> Result<usize> {
+    let mut file_index = 0;
+    let mut i = 0usize;
+    let mut end_file_index = if input.len().is_multiple_of(2) {
+        (input.len() - 2) / 2
+    } else {
+        (input.len() - 1) / 2
+    };
+    let mut end_file_consumed = 0usize;
+    let mut answer = 0usize;
+    while file_index * 2 < input.len() && file_index <= end_file_index {
+        // Block
+        let file_len = input[file_index * 2];
+        // println!("Processing block of {} at index {}", file_len, file_index);
+        if file_index == end_file_index {
+            answer += file_index * gauss_sum(i, file_len - end_file_consumed);
+            // println!("  Indices crossing over at {}, consuming {} of {} remaining blocks", file_index, file_len - end_file_consumed, file_len);
+            break;
+        }
+        answer += file_index * gauss_sum(i, file_len);
+        i += file_len;
+
+        // Gap
+        if file_index * 2 + 1 < input.len() {
+            let gap_len = input[file_index * 2 + 1];
+            // println!("Processing gap of {}", gap_len);
+            let mut remaining_blocks = gap_len;
+            while remaining_blocks > 0 {
+                let end_file_len = input[end_file_index * 2] - end_file_consumed;
+                // println!("  There are {} blocks of {} we can use", end_file_len, end_file_index);
+                if end_file_len >= remaining_blocks {
+                    // println!("  Consuming all {} blocks of {}", remaining_blocks, end_file_index);
+                    answer += end_file_index * gauss_sum(i, remaining_blocks);
+                    i += remaining_blocks;
+                    end_file_consumed += remaining_blocks;
+                    remaining_blocks = 0;
+                } else {
+                    // println!("  Consuming {} blocks of {}", end_file_len, end_file_index);
+                    answer += end_file_index * gauss_sum(i, end_file_len);
+                    i += end_file_len;
+                    end_file_index -= 1;
+                    end_file_consumed = 0;
+                    remaining_blocks -= end_file_len;
+                }
+            }
+        }
+
+        file_index += 1;
+    }
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+#[derive(Copy, Clone)]
+struct DiskBlock {
+    is_file: bool, // otherwise is gap
+    file_index: usize,
+    length: usize,
+}
+
+#[allow(dead_code)]
+fn print_blocks(blocks: &Vec<DiskBlock>) {
+    for block in blocks {
+        if block.is_file {
+            print!("{}", vec![block.file_index.to_string(); block.length].iter().join(""));
+        } else {
+            print!("{}", vec!["."; block.length].iter().join(""));
+        }
+    }
+    println!();
+}
+
+fn part2(input: &[usize]) -> Result<usize> {
+    let mut blocks = input.iter()
+        .enumerate()
+        .map(|(i, &length)| {
+            if i % 2 == 0 {
+                DiskBlock{is_file: true, file_index: i / 2, length}
+            } else {
+                DiskBlock{is_file: false, file_index: 0, length}
+            }
+        })
+        .collect::<Vec<DiskBlock>>();
+    
+    // print_blocks(&blocks);
+
+    for end_block_index in (0..blocks.len()).rev() {
+        let block = blocks[end_block_index];
+        if !block.is_file {
+            continue;
+        }
+
+        for gap_index in 0..end_block_index {
+            let gap = blocks[gap_index];
+            if gap.is_file {
+                continue;
+            }
+            if gap.length >= block.length {
+                blocks[end_block_index].is_file = false;
+                blocks[gap_index].length -= block.length;
+                blocks.insert(gap_index, block);
+
+                break;
+            }
+        }
+        
+        // print_blocks(&blocks);
+    }
+
+    let mut i = 0usize;
+    let mut answer = 0usize;
+    for block in blocks {
+        if block.is_file {
+            answer += block.file_index * gauss_sum(i, block.length);
+            i += block.length;
+        } else {
+            i += block.length;
+        }
+    }
+
+    Ok(answer)
+}
+//endregion
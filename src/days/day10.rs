@@ -0,0 +1,131 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "10";
+
+const TEST: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: &'static str = DAY;
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse(DAY, reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day10;
+    part1_test: part1(TEST) == 36;
+    part2_test: part2(TEST) == 81;
+}
+
+//region Part 1
+fn dfs(input: &Grid<u8>, start: Point) -> usize {
+    let mut stack: Vec<Point> = vec![start];
+
+    let mut seen = Grid::new(input.width(), input.height(), false);
+
+    let mut answer = 0;
+    while let Some(pos) = stack.pop() {
+        let cur_height = input[pos];
+        // println!("At {}, height: {}", pos, char::from(cur_height));
+
+        if cur_height == b'9' {
+            if !seen[pos] {
+                seen[pos] = true;
+                answer += 1;
+            }
+            continue;
+        }
+
+        for new_pos in input.neighbors4(pos) {
+            let new_height = input[new_pos];
+
+            if new_height == cur_height + 1 {
+                // println!("Pushing {} at {}", char::from(new_height), new_pos);
+                stack.push(new_pos);
+            }
+        }
+    }
+
+    answer
+}
+
+fn dfs2(input: &Grid<u8>, start: Point) -> usize {
+    let mut stack: Vec<Point> = vec![start];
+
+    let mut answer = 0;
+    while let Some(pos) = stack.pop() {
+        let cur_height = input[pos];
+        // println!("At {}, height: {}", pos, char::from(cur_height));
+
+        if cur_height == b'9' {
+            answer += 1;
+            continue;
+        }
+
+        for new_pos in input.neighbors4(pos) {
+            let new_height = input[new_pos];
+
+            if new_height == cur_height + 1 {
+                // println!("Pushing {} at {}", char::from(new_height), new_pos);
+                stack.push(new_pos);
+            }
+        }
+    }
+
+    answer
+}
+
+fn part1(input: &Grid<u8>) -> Result<usize> {
+    let mut answer = 0usize;
+    for pos in input.find_all(&b'0') {
+        let num_trails = dfs(input, pos);
+        // println!("Found {} trails starting from {}", num_trails, pos);
+        answer += num_trails;
+    }
+
+    // println!("num trails: {}", dfs(&input, Point::new(6, 6)));
+
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+fn part2(input: &Grid<u8>) -> Result<usize> {
+    let mut answer = 0usize;
+    for pos in input.find_all(&b'0') {
+        let num_trails = dfs2(input, pos);
+        // println!("Found {} trails starting from {}", num_trails, pos);
+        answer += num_trails;
+    }
+
+    // println!("num trails: {}", dfs(&input, Point::new(6, 6)));
+
+    Ok(answer)
+}
+//endregion
@@ -0,0 +1,126 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "11";
+
+const TEST: &str = "\
+125 17
+";
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: &'static str = DAY;
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = parse::read_all(reader)?;
+        let line = parse::lines(DAY, &input).next().ok_or(Error::msg("There are no stones"))?;
+        line.parse_list(line.text.trim_end(), " ")
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day11;
+    part1_test: part1(TEST) == 55312;
+}
+
+//region Part 1
+fn num_digits(value: usize) -> usize {
+    let mut digits = 1usize;
+    let mut power = 10usize;
+    while power <= value {
+        power *= 10;
+        digits += 1;
+    }
+
+    digits
+}
+
+fn pow(base: usize, exponent: usize) -> usize {
+    let mut result = 1;
+    for _ in 0..exponent {
+        result *= base;
+    }
+    result
+}
+
+fn num_stones(value: usize, steps: usize) -> usize {
+    // println!("Computing {} at step {}", value, steps);
+    if steps == 0 {
+        return 1;
+    }
+
+    if value == 0 {
+        return num_stones(1, steps - 1);
+    }
+
+    let digits = num_digits(value);
+    // println!("  {} digits", digits);
+    if digits.is_multiple_of(2) {
+        let half_power = pow(10, digits / 2);
+        // println!("  {} half power", half_power);
+        let left = value / half_power;
+        let right = value % half_power;
+        // println!("  Trying {} and {}", left, right);
+        return num_stones(left, steps - 1) + num_stones(right, steps - 1);
+    }
+
+    num_stones(value * 2024, steps - 1)
+}
+
+fn part1(input: &[usize]) -> Result<usize> {
+    let answer = input.iter().map(|value| num_stones(*value, 25)).sum();
+
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+fn num_stones2(value: usize, steps: usize, memo: &mut Memo<(usize, usize), usize>) -> usize {
+    // println!("Computing {} at step {}", value, steps);
+    if steps == 0 {
+        return 1;
+    }
+
+    memo.get_or_compute((value, steps), |memo| {
+        if value == 0 {
+            return num_stones2(1, steps - 1, memo);
+        }
+
+        let digits = num_digits(value);
+        // println!("  {} digits", digits);
+        if digits.is_multiple_of(2) {
+            let half_power = pow(10, digits / 2);
+            // println!("  {} half power", half_power);
+            let left = value / half_power;
+            let right = value % half_power;
+            // println!("  Trying {} and {}", left, right);
+            return num_stones2(left, steps - 1, memo) + num_stones2(right, steps - 1, memo);
+        }
+
+        num_stones2(value * 2024, steps - 1, memo)
+    })
+}
+
+fn part2(input: &[usize]) -> Result<usize> {
+    let mut memo = Memo::new();
+    let answer = input.iter().map(|value| num_stones2(*value, 75, &mut memo)).sum();
+    // println!("{}", memo.stats());
+
+    Ok(answer)
+}
+
+//endregion
@@ -0,0 +1,140 @@
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "12";
+
+const TEST: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: &'static str = DAY;
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse(DAY, reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day12;
+    part1_test: part1(TEST) == 1930;
+    part2_test: part2(TEST) == 1206;
+}
+
+//region Part 1
+fn dfs(input: &Grid<u8>, start: Point, seen: &mut HashSet<Point>) -> usize {
+    let mut stack: Vec<Point> = vec![start];
+    let mut perimeter = 0usize;
+    let mut area = 0usize;
+    while let Some(pos) = stack.pop() {
+        if !seen.insert(pos) {
+            continue;
+        }
+
+        area += 1;
+
+        let cur_val = input[pos];
+        for dir in Direction::ALL {
+            let next_pos = pos.step(dir);
+
+            if input.get(next_pos) == Some(&cur_val) {
+                stack.push(next_pos);
+            } else {
+                // Either off the map or a different plant
+                perimeter += 1;
+            }
+        }
+    }
+
+    area * perimeter
+}
+
+fn part1(input: &Grid<u8>) -> Result<usize> {
+    let mut seen: HashSet<Point> = HashSet::new();
+    let mut answer = 0usize;
+    for pos in input.positions() {
+        answer += dfs(input, pos, &mut seen);
+    }
+
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+fn dfs2(input: &Grid<u8>, start: Point, seen: &mut HashSet<Point>) -> usize {
+    let mut stack: Vec<Point> = vec![start];
+    let mut perimeter = 0usize;
+    let mut area = 0usize;
+    while let Some(pos) = stack.pop() {
+        if !seen.insert(pos) {
+            continue;
+        }
+
+        area += 1;
+
+        let cur_val = input[pos];
+        for dir in Direction::ALL {
+            let next_pos = pos.step(dir);
+
+            let next_dir = dir.turn_right();
+            let next_next_pos = pos.step(next_dir);
+
+            let diag_pos = next_pos.step(next_dir);
+
+            // Off the map counts as a different plant
+            let side1 = input.get(next_pos) != Some(&cur_val);
+            let side2 = input.get(next_next_pos) != Some(&cur_val);
+            let diag = input.get(diag_pos) != Some(&cur_val);
+
+            if side1 && side2 {
+                // Outer corner
+                perimeter += 1;
+            } else if !side1 && !side2 && diag {
+                // Inner corner
+                perimeter += 1;
+            }
+            
+            if !side1 {
+                stack.push(next_pos);
+            }
+        }
+    }
+
+    area * perimeter
+}
+
+fn part2(input: &Grid<u8>) -> Result<usize> {
+    let mut seen: HashSet<Point> = HashSet::new();
+    let mut answer = 0usize;
+    for pos in input.positions() {
+        answer += dfs2(input, pos, &mut seen);
+    }
+
+    Ok(answer)
+}
+//endregion
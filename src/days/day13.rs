@@ -0,0 +1,134 @@
+use anyhow::*;
+use std::cmp::min;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "13";
+
+const TEST: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: &'static str = DAY;
+    type Input = Vec<Machine>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse(reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day13;
+    part1_test: part1(TEST) == 480;
+}
+
+/// Movement of the A and B buttons, then where the prize is
+type Machine = [(usize, usize); 3];
+
+fn parse<R: BufRead>(reader: R) -> Result<Vec<Machine>> {
+    let input = parse::read_all(reader)?;
+    parse::sections(DAY, &input).iter()
+        .map(|block| {
+            let [a, b, prize] = block.as_slice() else {
+                let last = block[block.len() - 1];
+                return Err(last.error_at(last.text.len(), format!("Expected 3 lines per machine, got {}", block.len())));
+            };
+            Ok([
+                extract!(a, "Button A: X+{}, Y+{}" => usize, usize)?,
+                extract!(b, "Button B: X+{}, Y+{}" => usize, usize)?,
+                extract!(prize, "Prize: X={}, Y={}" => usize, usize)?,
+            ])
+        })
+        .collect()
+}
+
+//region Part 1
+// There is only one possible solution, the "find the minimum tokens" is a red herring
+// Imagine it as two vectors that add to a goal vector. We binary search the length of the
+// first vector such that the second vector can reach the goal with an integer multiple.
+fn f1(goal: (usize, usize), a: (usize, usize), b: (usize, usize)) -> Option<usize> {
+    let low_slope = (goal.0 as f64 / a.0 as f64) - (goal.1 as f64 / a.1 as f64) < 0.0;
+    let mut num_b_low = 0;
+    let mut num_b_high = min(goal.0 / b.0, goal.1 / b.1);
+    while num_b_high > num_b_low {
+        let num_b = (num_b_low + num_b_high) / 2;
+        // println!("{} ({} to {})", num_b, num_b_low, num_b_high);
+        let remaining_x = goal.0 - (b.0 * num_b);
+        let remaining_y = goal.1 - (b.1 * num_b);
+
+        let num_a_x = remaining_x / a.0;
+        let num_a_y = remaining_y / a.1;
+
+        if remaining_x.is_multiple_of(a.0) && remaining_y.is_multiple_of(a.1) && num_a_x == num_a_y {
+            // We've found the solution
+            // println!("    Found solution {} a's and {} b's for total {}", num_a_x, num_b, num_a_x * 3 + num_b);
+            return Some(num_a_x * 3 + num_b);
+        }
+
+        let slope = (remaining_x as f64 / a.0 as f64) - (remaining_y as f64 / a.1 as f64) < 0.0;
+        if slope == low_slope {
+            // println!("  Low side of slope: {} {}", num_a_x, num_a_y);
+            // We're still on the low side of num_b
+            num_b_low = num_b + 1;
+        } else {
+            // println!("  High side of slope: {} {}", num_a_x, num_a_y);
+            num_b_high = num_b;
+        }
+    }
+
+    None
+}
+
+fn part1(machines: &[Machine]) -> Result<usize> {
+    let mut answer = 0usize;
+    machines.iter().for_each(|&[a, b, prize]| {
+        // println!("Trying to reach {:?} using A {:?} and B {:?}", prize, a, b);
+        let min_cost = f1(prize, a, b).unwrap_or(0usize);
+        answer += min_cost;
+    });
+    Ok(answer)
+}
+
+// f1((1409, 2686), (96, 72), (17, 46));
+//endregion
+
+//region Part 2
+fn part2(machines: &[Machine]) -> Result<usize> {
+    let mut answer = 0usize;
+    machines.iter().for_each(|&[a, b, (p_x, p_y)]| {
+        // println!("Trying to reach {}, {} using A {:?} and B {:?}", p_x, p_y, a, b);
+        let min_cost = f1((p_x + 10000000000000, p_y + 10000000000000), a, b).unwrap_or(0usize);
+        // println!("  min cost {}", min_cost);
+        answer += min_cost;
+    });
+    Ok(answer)
+}
+//endregion
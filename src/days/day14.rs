@@ -0,0 +1,204 @@
+use anyhow::*;
+use std::io::BufRead;
+use std::str::FromStr;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "14";
+
+const TEST: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+pub mod arena;
+
+use arena::RobotArena;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: &'static str = DAY;
+    type Input = Vec<Precondition>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse(reader)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<usize> {
+        part1(&RobotArena::new(params.width, params.height, input)?)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<usize> {
+        part2(&RobotArena::new(params.width, params.height, input)?)
+    }
+}
+
+examples! {
+    Day14;
+    part1_test: part1(TEST, Params { width: 11, height: 7, ..Params::default() }) == 12;
+}
+
+#[derive(Clone, Debug)]
+pub struct Precondition {
+    x: i32,
+    y: i32,
+    vx: i32,
+    vy: i32,
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<Vec<Precondition>> {
+    let input = parse::read_all(reader)?;
+    parse::parse_records(DAY, &input)
+}
+
+impl FromStr for Precondition {
+    type Err = Error;
+
+    // p=x,y v=vx,vy
+    fn from_str(s: &str) -> Result<Self> {
+        let [x, y, vx, vy] = parse::ints_in_line(s)?[..] else {
+            bail!("Expected a position and a velocity");
+        };
+        Ok(Precondition { x, y, vx, vy })
+    }
+}
+
+//region Part 1
+fn part1(arena: &RobotArena) -> Result<usize> {
+    // println!("{}", arena.render(100));
+    // println!("Quadrants {:?}", arena.quadrants_at(100));
+    Ok(arena.safety_factor(100))
+}
+//endregion
+
+//region Part 2
+/// The robots bunch up to draw the tree, so it shows up when their positions vary the least. x
+/// repeats every `width` seconds and y every `height`, so each axis has its own best time, and the
+/// Chinese remainder theorem turns the two into the first time they line up.
+fn part2(arena: &RobotArena) -> Result<usize> {
+    let (input, width, height) = (arena.robots(), arena.width() as i32, arena.height() as i32);
+    ensure!(!input.is_empty(), "There are no robots");
+    let time_x = least_spread(input.iter().map(|robot| (robot.x, robot.vx)), width)
+        .ok_or(Error::msg("The robots never bunch up horizontally"))?;
+    let time_y = least_spread(input.iter().map(|robot| (robot.y, robot.vy)), height)
+        .ok_or(Error::msg("The robots never bunch up vertically"))?;
+    // println!("Least spread out at {} mod {} and {} mod {}", time_x, width, time_y, height);
+
+    let time = crt(time_x, width as i64, time_y, height as i64)
+        .ok_or_else(|| Error::msg(format!("No time is {} mod {} and {} mod {}", time_x, width, time_y, height)))?;
+    Ok(time as usize)
+}
+
+// The time in 0..size when the positions along one axis vary the least, if that stands out from
+// the average
+fn least_spread(robots: impl Iterator<Item = (i32, i32)> + Clone, size: i32) -> Option<i64> {
+    // n² times the variance, which keeps it in integers, and wide enough for any room and robot count
+    let size = size as i64;
+    let spreads = (0..size)
+        .map(|time| {
+            let (n, sum, sum_squares) = robots.clone()
+                .map(|(pos, v)| (pos as i64 + v as i64 * time).rem_euclid(size) as i128)
+                .fold((0, 0, 0), |(n, sum, sum_squares), pos| (n + 1, sum + pos, sum_squares + pos * pos));
+            n * sum_squares - sum * sum
+        })
+        .collect::<Vec<i128>>();
+
+    let (time, &least) = spreads.iter().enumerate().min_by_key(|&(_, spread)| spread)?;
+    let average = spreads.iter().sum::<i128>() / spreads.len() as i128;
+    // println!("  Spread {} at {}, {} on average", least, time, average);
+    // Random positions barely move the variance, a picture pulls it well below average
+    (least * 4 < average * 3).then_some(time as i64)
+}
+
+// The smallest t ≥ 0 with t ≡ a (mod m) and t ≡ b (mod n), if there is one
+fn crt(a: i64, m: i64, b: i64, n: i64) -> Option<i64> {
+    let (g, p, _) = extended_gcd(m, n);
+    if (b - a) % g != 0 {
+        return None;
+    }
+    let lcm = m / g * n;
+    Some((a + m * ((b - a) / g * p % (n / g))).rem_euclid(lcm))
+}
+
+// (g, x, y) with a * x + b * y = g = gcd(a, b)
+fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+//endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Robots that draw a filled square at `time`, with as many again moving about at random
+    fn drawing_at(time: i32, width: i32, height: i32) -> RobotArena {
+        let mut seed = 0x2024u64;
+        let mut random = |below: i32| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((seed >> 33) % below as u64) as i32
+        };
+        let robots = (0..200)
+            .map(|i| {
+                let (vx, vy) = (random(2 * width) - width, random(2 * height) - height);
+                let (x, y) = if i % 2 == 0 {
+                    (width / 3 + i / 2 % 10, height / 3 + i / 20)
+                } else {
+                    (random(width), random(height))
+                };
+                Precondition {
+                    x: (x - vx * time).rem_euclid(width),
+                    y: (y - vy * time).rem_euclid(height),
+                    vx,
+                    vy,
+                }
+            })
+            .collect::<Vec<_>>();
+        RobotArena::new(width as usize, height as usize, &robots).unwrap()
+    }
+
+    #[test]
+    fn finds_the_drawing() {
+        assert_eq!(500, part2(&drawing_at(500, 31, 37)).unwrap());
+        let arena = drawing_at(7000, 101, 103);
+        assert_eq!(7000, part2(&arena).unwrap());
+        assert!(arena.clustering_at(7000).longest_run >= 10);
+        // Nothing to find when every robot moves about at random
+        let noise = drawing_at(0, 31, 37).robots().iter().skip(1).step_by(2).cloned().collect::<Vec<_>>();
+        assert!(part2(&RobotArena::new(31, 37, &noise).unwrap()).is_err());
+    }
+
+    #[test]
+    fn copes_with_fast_robots() {
+        // Going round the room millions of times a second ends up in the same place
+        let arena = drawing_at(500, 31, 37);
+        let fast = arena.robots().iter()
+            .map(|robot| Precondition { vx: robot.vx + 31 * 60_000_000, vy: robot.vy - 37 * 50_000_000, ..robot.clone() })
+            .collect::<Vec<_>>();
+        assert_eq!(500, part2(&RobotArena::new(31, 37, &fast).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn combines_the_axes() {
+        assert_eq!(Some(8), crt(2, 3, 3, 5));
+        assert_eq!(Some(10), crt(4, 6, 2, 8));
+        assert_eq!(None, crt(1, 4, 2, 6));
+    }
+}
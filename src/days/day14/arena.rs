@@ -0,0 +1,209 @@
+use anyhow::*;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::ops::Range;
+use std::path::Path;
+use crate::*;
+use super::Precondition;
+
+/// The robots in a room of any size, which can be looked at any number of seconds in.
+#[derive(Clone, Debug)]
+pub struct RobotArena {
+    width: usize,
+    height: usize,
+    robots: Vec<Precondition>,
+}
+
+/// How bunched up the robots are at some time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Clustering {
+    /// Variance of the x and of the y positions
+    pub variance: (f64, f64),
+    /// Robots with another robot on one of the eight tiles around them
+    pub with_neighbors: usize,
+    /// Longest stretch of tiles in a row that all have a robot
+    pub longest_run: usize,
+}
+
+impl RobotArena {
+    pub fn new(width: usize, height: usize, robots: &[Precondition]) -> Result<Self> {
+        ensure!(width > 0 && height > 0, "The room can't be {}x{}", width, height);
+        ensure!(width <= i32::MAX as usize && height <= i32::MAX as usize, "The room can't be {}x{}", width, height);
+        if let Some(robot) = robots.iter().find(|robot| robot.x < 0 || robot.y < 0 || robot.x as usize >= width || robot.y as usize >= height) {
+            bail!("The robot at {},{} is outside of the {}x{} room", robot.x, robot.y, width, height);
+        }
+        Ok(RobotArena { width, height, robots: robots.to_vec() })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn robots(&self) -> &[Precondition] {
+        &self.robots
+    }
+
+    /// Where each robot is `time` seconds in, which can be negative to go back in time.
+    pub fn positions_at(&self, time: i64) -> Vec<Point> {
+        self.robots.iter()
+            .map(|robot| Point::new(
+                wrap(robot.x, robot.vx, time, self.width),
+                wrap(robot.y, robot.vy, time, self.height),
+            ))
+            .collect()
+    }
+
+    /// Robots in the top left, top right, bottom left and bottom right quadrants, leaving out the
+    /// ones on the middle row or column.
+    pub fn quadrants_at(&self, time: i64) -> [usize; 4] {
+        let (mid_x, mid_y) = ((self.width / 2) as i32, (self.height / 2) as i32);
+        let mut quadrants = [0; 4];
+        for pos in self.positions_at(time) {
+            if pos.x == mid_x && self.width % 2 == 1 || pos.y == mid_y && self.height % 2 == 1 {
+                continue;
+            }
+            quadrants[(pos.y >= mid_y) as usize * 2 + (pos.x >= mid_x) as usize] += 1;
+        }
+        quadrants
+    }
+
+    /// The product of the quadrant counts.
+    pub fn safety_factor(&self, time: i64) -> usize {
+        self.quadrants_at(time).iter().product()
+    }
+
+    /// Robots per block of `cell` by `cell` tiles, with the blocks on the right and bottom edges
+    /// cut short if the room doesn't divide evenly.
+    pub fn heatmap(&self, time: i64, cell: usize) -> Grid<usize> {
+        let cell = cell.max(1);
+        let mut heatmap = Grid::new(self.width.div_ceil(cell), self.height.div_ceil(cell), 0);
+        for pos in self.positions_at(time) {
+            heatmap[Point::new(pos.x / cell as i32, pos.y / cell as i32)] += 1;
+        }
+        heatmap
+    }
+
+    /// The room with the number of robots on each tile, `.` for none and `+` for more than 9.
+    pub fn render(&self, time: i64) -> String {
+        let heatmap = self.heatmap(time, 1);
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in heatmap.rows() {
+            text.extend(row.iter().map(|&count| match count {
+                0 => '.',
+                1..=9 => char::from(b'0' + count as u8),
+                _ => '+',
+            }));
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn clustering_at(&self, time: i64) -> Clustering {
+        let positions = self.positions_at(time);
+        let occupied = self.heatmap(time, 1).map(|&count| count > 0);
+
+        let variance = |axis: fn(&Point) -> i32| {
+            let n = positions.len().max(1) as f64;
+            let mean = positions.iter().map(|pos| axis(pos) as f64).sum::<f64>() / n;
+            positions.iter().map(|pos| (axis(pos) as f64 - mean).powi(2)).sum::<f64>() / n
+        };
+        let with_neighbors = positions.iter()
+            .filter(|&&pos| occupied.neighbors8(pos).any(|neighbor| occupied[neighbor]))
+            .count();
+        let longest_run = occupied.rows()
+            .flat_map(|row| row.split(|&robot| !robot).map(|run| run.len()))
+            .max()
+            .unwrap_or(0);
+
+        Clustering { variance: (variance(|pos| pos.x), variance(|pos| pos.y)), with_neighbors, longest_run }
+    }
+
+    /// Writes every robot's position at each time in `times` as CSV, with `time,robot,x,y` columns.
+    pub fn write_csv(&self, path: impl AsRef<Path>, times: Range<i64>) -> Result<()> {
+        let path = path.as_ref();
+        let file = File::create(path).with_context(|| format!("Couldn't create {}", path.display()))?;
+        let mut out = BufWriter::new(file);
+        writeln!(out, "time,robot,x,y")?;
+        for time in times {
+            for (robot, pos) in self.positions_at(time).into_iter().enumerate() {
+                writeln!(out, "{},{},{},{}", time, robot, pos.x, pos.y)?;
+            }
+        }
+        out.flush().with_context(|| format!("Couldn't write {}", path.display()))
+    }
+}
+
+// Position along one axis, reducing time first so that the velocity can't overflow it
+fn wrap(pos: i32, v: i32, time: i64, size: usize) -> i32 {
+    let size = size as i64;
+    (pos as i64 + v as i64 * time.rem_euclid(size)).rem_euclid(size) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+    use super::super::{Day14, TEST};
+
+    fn example() -> RobotArena {
+        RobotArena::new(11, 7, &Day14::parse(TEST.as_bytes()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn moves_robots_in_closed_form() {
+        let arena = RobotArena::new(11, 7, &[Precondition { x: 2, y: 4, vx: 2, vy: -3 }]).unwrap();
+        let steps = (0..=5).map(|time| arena.positions_at(time)[0]).collect::<Vec<_>>();
+        assert_eq!(
+            vec![Point::new(2, 4), Point::new(4, 1), Point::new(6, 5), Point::new(8, 2), Point::new(10, 6), Point::new(1, 3)],
+            steps,
+        );
+        assert_eq!(arena.positions_at(5), arena.positions_at(5 + 77 * 1_000_000_000_000));
+        assert_eq!(arena.positions_at(0), arena.positions_at(-77));
+    }
+
+    #[test]
+    fn analyses_the_example() {
+        let arena = example();
+        assert_eq!([1, 3, 4, 1], arena.quadrants_at(100));
+        assert_eq!(12, arena.safety_factor(100));
+        assert_eq!("\
+......2..1.
+...........
+1..........
+.11........
+.....1.....
+...12......
+.1....1....
+", arena.render(100));
+
+        let heatmap = arena.heatmap(100, 4);
+        assert_eq!((3, 2), (heatmap.width(), heatmap.height()));
+        assert_eq!(12, heatmap.iter().map(|(_, &count)| count).sum::<usize>());
+
+        let clustering = arena.clustering_at(100);
+        assert_eq!((7, 2), (clustering.with_neighbors, clustering.longest_run));
+        assert!(clustering.variance.0 > clustering.variance.1);
+    }
+
+    #[test]
+    fn exports_csv() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("aoc-arena-test-{}.csv", std::process::id()));
+        example().write_csv(&path, 99..101)?;
+        let csv = std::fs::read_to_string(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(1 + 2 * 12, csv.lines().count());
+        assert!(csv.starts_with("time,robot,x,y\n99,0,"));
+        assert!(csv.ends_with("100,11,6,6\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_robots_outside_the_room() {
+        assert!(RobotArena::new(11, 7, &[Precondition { x: 11, y: 0, vx: 0, vy: 0 }]).is_err());
+        assert!(RobotArena::new(0, 7, &[]).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "15";
+
+const TEST: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+pub mod interactive;
+pub mod warehouse;
+
+use warehouse::Warehouse;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: &'static str = DAY;
+    /// The warehouse, then the robot's moves
+    type Input = (Grid<u8>, Vec<Direction>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = parse::read_all(reader)?;
+        let [map, moves] = parse::exact_sections(DAY, &input)?;
+        let map = Grid::from_lines(map)?;
+        let moves = moves.iter()
+            .flat_map(|line| line.text.char_indices().map(move |(i, c)| {
+                Direction::try_from(c).map_err(|e| line.error_at(i, e))
+            }))
+            .collect::<Result<Vec<Direction>>>()?;
+        Ok((map, moves))
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day15;
+    part1_test: part1(TEST) == 10092;
+    part2_test: part2(TEST) == 9021;
+}
+
+//region Part 1
+fn part1((map, moves): &(Grid<u8>, Vec<Direction>)) -> Result<usize> {
+    let mut warehouse = Warehouse::new(map.clone())?;
+    warehouse.run(moves);
+    // print!("{}", warehouse.map());
+    Ok(warehouse.gps_sum())
+}
+//endregion
+
+//region Part 2
+fn part2((map, moves): &(Grid<u8>, Vec<Direction>)) -> Result<usize> {
+    let mut warehouse = Warehouse::new(warehouse::widen(map)?)?;
+    warehouse.run(moves);
+    Ok(warehouse.gps_sum())
+}
+//endregion
@@ -0,0 +1,194 @@
+use anyhow::{ensure, Result};
+use crossterm::event::{self, Event as TerminalEvent, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::{cursor, terminal, ExecutableCommand};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use crate::*;
+use super::warehouse::{self, Warehouse};
+
+/// What a key does in [play].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Move(Direction),
+    Undo,
+    Redo,
+    Save,
+    Quit,
+}
+
+impl Command {
+    pub fn from_key(code: KeyCode) -> Option<Command> {
+        let command = match code {
+            KeyCode::Up => Command::Move(Direction::Up),
+            KeyCode::Down => Command::Move(Direction::Down),
+            KeyCode::Left => Command::Move(Direction::Left),
+            KeyCode::Right => Command::Move(Direction::Right),
+            KeyCode::Char('u') | KeyCode::Backspace => Command::Undo,
+            KeyCode::Char('r') => Command::Redo,
+            KeyCode::Char('s') => Command::Save,
+            KeyCode::Char('q') | KeyCode::Esc => Command::Quit,
+            KeyCode::Char(c) => Command::Move(Direction::try_from(c).ok()?),
+            _ => return None,
+        };
+        Some(command)
+    }
+}
+
+/// Driving the robot around the warehouse by hand, with the moves so far saved as a puzzle input.
+pub struct Session {
+    /// The map the way it is in the input, before any widening
+    map: Grid<u8>,
+    warehouse: Warehouse,
+    save_to: PathBuf,
+    status: String,
+}
+
+impl Session {
+    pub fn new(map: Grid<u8>, wide: bool, save_to: PathBuf) -> Result<Self> {
+        let warehouse = Warehouse::new(if wide { warehouse::widen(&map)? } else { map.clone() })?;
+        Ok(Session { map, warehouse, save_to, status: String::new() })
+    }
+
+    pub fn warehouse(&self) -> &Warehouse {
+        &self.warehouse
+    }
+
+    /// The moves made so far, leaving out any that were undone.
+    pub fn moves(&self) -> Vec<Direction> {
+        self.warehouse.events()[..self.warehouse.position()].iter()
+            .map(|event| event.direction)
+            .collect()
+    }
+
+    /// The starting map and the moves so far, as a puzzle input. There's no such thing as an input
+    /// without moves, so that's an error.
+    pub fn to_input(&self) -> Result<String> {
+        let moves = self.moves();
+        ensure!(!moves.is_empty(), "Nothing to save until the robot has moved");
+        Ok(format!("{}\n{}", self.map, Direction::format_moves(&moves, 70)))
+    }
+
+    /// Carries out the command, returning false once it's time to quit.
+    pub fn handle(&mut self, command: Command) -> bool {
+        self.status = match command {
+            Command::Move(direction) => self.warehouse.push(direction).to_string(),
+            Command::Undo => match self.warehouse.step_back() {
+                Some(event) => format!("Undid {}", event),
+                None => "Nothing to undo".to_string(),
+            },
+            Command::Redo => match self.warehouse.step_forward() {
+                Some(event) => format!("Redid {}", event),
+                None => "Nothing to redo".to_string(),
+            },
+            Command::Save => match self.to_input() {
+                Ok(input) => match fs::write(&self.save_to, input) {
+                    Ok(()) => format!("Saved {} moves to {}", self.warehouse.position(), self.save_to.display()),
+                    Err(e) => format!("Couldn't save to {}: {}", self.save_to.display(), e),
+                },
+                Err(e) => e.to_string(),
+            },
+            Command::Quit => return false,
+        };
+        true
+    }
+
+    /// The map, the GPS sum and what happened last.
+    pub fn render(&self) -> String {
+        format!("{}\nGPS sum: {}   Moves: {}   {}\n\nArrows or ^v<> move, u undoes, r redoes, s saves to {}, q quits\n",
+                self.warehouse.map(), self.warehouse.gps_sum(), self.warehouse.position(), self.status,
+                self.save_to.display())
+    }
+}
+
+/// Plays the session in the terminal until the player quits.
+pub fn play(session: &mut Session) -> Result<()> {
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    stdout.execute(terminal::EnterAlternateScreen)?.execute(cursor::Hide)?;
+
+    let result = (|| -> Result<()> {
+        loop {
+            stdout.execute(terminal::Clear(terminal::ClearType::All))?.execute(cursor::MoveTo(0, 0))?;
+            // Raw mode doesn't go back to the start of the line on a newline
+            write!(stdout, "{}", session.render().replace('\n', "\r\n"))?;
+            stdout.flush()?;
+
+            if let TerminalEvent::Key(KeyEvent { code, kind: KeyEventKind::Press, .. }) = event::read()? {
+                if let Some(command) = Command::from_key(code) {
+                    if !session.handle(command) {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    })();
+
+    // Put the terminal back the way it was, even if playing failed
+    stdout.execute(cursor::Show)?.execute(terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Params, Solution};
+    use super::super::Day15;
+
+    const MAP: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+";
+
+    #[test]
+    fn saves_only_once_the_robot_has_moved() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("aoc-play-empty-test-{}.txt", std::process::id()));
+        let mut session = Session::new(MAP.parse()?, false, path.clone())?;
+        assert!(session.to_input().is_err());
+        assert!(session.handle(Command::Save));
+        assert!(!path.exists());
+        assert!(session.render().contains("Nothing to save until the robot has moved"));
+
+        // Undoing every move leaves nothing to save again
+        session.handle(Command::Move(Direction::Left));
+        session.handle(Command::Undo);
+        assert!(session.to_input().is_err());
+
+        // One move is enough to make an input that reads back the same
+        session.handle(Command::Redo);
+        assert!(session.handle(Command::Save));
+        let (map, moves) = Day15::parse(fs::read_to_string(&path)?.as_bytes())?;
+        fs::remove_file(&path)?;
+        assert_eq!((MAP.parse::<Grid<u8>>()?, vec![Direction::Left]), (map, moves));
+        Ok(())
+    }
+
+    #[test]
+    fn plays_and_saves_a_game() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("aoc-play-test-{}.txt", std::process::id()));
+        let mut session = Session::new(MAP.parse()?, false, path.clone())?;
+        for key in [KeyCode::Left, KeyCode::Up, KeyCode::Char('^'), KeyCode::Right, KeyCode::Right, KeyCode::Char('u')] {
+            assert!(session.handle(Command::from_key(key).unwrap()));
+        }
+        assert_eq!(vec![Direction::Left, Direction::Up, Direction::Up, Direction::Right], session.moves());
+        assert!(session.render().contains(&format!("GPS sum: {}   Moves: 4   Undid > pushing 2 boxes\n", session.warehouse().gps_sum())));
+
+        assert!(session.handle(Command::Save));
+        let saved = fs::read_to_string(&path)?;
+        assert_eq!(format!("{}\n<^^>\n", MAP), saved);
+        let input = Day15::parse(saved.as_bytes())?;
+        assert_eq!(session.warehouse().gps_sum(), Day15::part1(&input, &Params::default())?);
+        fs::remove_file(&path)?;
+
+        assert!(!session.handle(Command::from_key(KeyCode::Esc).unwrap()));
+        assert_eq!(None, Command::from_key(KeyCode::Char('x')));
+        Ok(())
+    }
+}
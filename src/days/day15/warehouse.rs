@@ -0,0 +1,467 @@
+use anyhow::*;
+use std::collections::HashSet;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::Path;
+use crate::*;
+
+/// The robot pushing boxes around the warehouse, recording every move as an [Event] so that the
+/// moves can be stepped through backwards and forwards, and exported as frames.
+///
+/// Boxes are `O`, `[]` in a [widen]ed warehouse, or any of the other shapes [Warehouse::box_at]
+/// knows about.
+#[derive(Clone, Debug)]
+pub struct Warehouse {
+    map: Grid<u8>,
+    robot: Point,
+    events: Vec<Event>,
+    /// How many of the events have been applied to the map
+    applied: usize,
+}
+
+/// One move of the robot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub direction: Direction,
+    /// Where the robot was before the move
+    pub robot: Point,
+    /// False if something was in the way
+    pub moved: bool,
+    /// How many boxes the robot pushed
+    pub pushed: usize,
+    /// Every cell that changed, with what was there before and after
+    pub changes: Vec<(Point, u8, u8)>,
+}
+
+fn is_box(c: u8) -> bool {
+    matches!(c, b'O' | b'[' | b'=' | b']') || is_shape(c)
+}
+
+// Letters other than O make boxes of any shape
+fn is_shape(c: u8) -> bool {
+    c.is_ascii_alphabetic() && c != b'O'
+}
+
+// Whether the tiles `a` and `b`, next to each other with `dx` the step from `a` to `b`, are parts
+// of the same box
+fn same_box(a: u8, b: u8, dx: i32) -> bool {
+    let (left, right) = match dx {
+        1 => (a, b),
+        -1 => (b, a),
+        _ => return a == b && is_shape(a),
+    };
+    matches!((left, right), (b'[' | b'=', b'=' | b']')) || (a == b && is_shape(a))
+}
+
+/// Doubles the width of the warehouse for part 2: boxes become `[]` and the robot stays on the
+/// left half of its cell.
+pub fn widen(map: &Grid<u8>) -> Result<Grid<u8>> {
+    let rows = map.rows()
+        .map(|row| {
+            row.iter()
+                .flat_map(|c| match c {
+                    b'#' => Some([b'#', b'#']),
+                    b'O' => Some([b'[', b']']),
+                    b'@' => Some([b'@', b'.']),
+                    b'.' => Some([b'.', b'.']),
+                    _ => None,
+                })
+                .flatten()
+                .collect::<Vec<u8>>()
+        })
+        .collect::<Vec<Vec<u8>>>();
+    ensure!(rows.iter().all(|row| row.len() == 2 * map.width()), "Unknown tile in the warehouse");
+    Grid::from_rows(rows)
+}
+
+impl Warehouse {
+    pub fn new(map: Grid<u8>) -> Result<Self> {
+        let robot = map.find(&b'@').ok_or(Error::msg("No robot found"))?;
+        Ok(Warehouse { map, robot, events: Vec::new(), applied: 0 })
+    }
+
+    pub fn map(&self) -> &Grid<u8> {
+        &self.map
+    }
+
+    pub fn robot(&self) -> Point {
+        self.robot
+    }
+
+    /// Every recorded move, including any that were stepped back over.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// How many of the [Warehouse::events] the map is showing the result of.
+    pub fn position(&self) -> usize {
+        self.applied
+    }
+
+    /// Sum of the GPS coordinates of all boxes, measured from their top and left edges for boxes
+    /// bigger than a tile.
+    pub fn gps_sum(&self) -> usize {
+        self.boxes().iter()
+            .map(|cells| {
+                let top = cells.iter().map(|pos| pos.y).min().unwrap_or_default();
+                let left = cells.iter().map(|pos| pos.x).min().unwrap_or_default();
+                top as usize * 100 + left as usize
+            })
+            .sum()
+    }
+
+    /// Moves the robot, pushing whatever boxes are in the way unless a wall stops them. Any moves
+    /// that were stepped back over are forgotten.
+    pub fn push(&mut self, direction: Direction) -> &Event {
+        self.events.truncate(self.applied);
+        let (cells, pushed) = match self.dry_run(direction) {
+            Some(boxes) => {
+                let cells = std::iter::once(self.robot).chain(boxes.iter().flatten().copied()).collect();
+                (cells, boxes.len())
+            }
+            None => (Vec::new(), 0),
+        };
+        let changes = self.changes(&cells, direction);
+        let event = Event { direction, robot: self.robot, moved: !changes.is_empty(), pushed, changes };
+        self.events.push(event);
+        self.step_forward();
+        &self.events[self.applied - 1]
+    }
+
+    pub fn run(&mut self, moves: &[Direction]) {
+        for &direction in moves {
+            self.push(direction);
+            // print!("{}", self.map);
+        }
+    }
+
+    /// Undoes the last applied move.
+    pub fn step_back(&mut self) -> Option<&Event> {
+        let event = self.events.get(self.applied.checked_sub(1)?)?;
+        for &(pos, before, _) in &event.changes {
+            self.map[pos] = before;
+        }
+        self.robot = event.robot;
+        self.applied -= 1;
+        Some(event)
+    }
+
+    /// Redoes the next move that was stepped back over.
+    pub fn step_forward(&mut self) -> Option<&Event> {
+        let event = self.events.get(self.applied)?;
+        for &(pos, _, after) in &event.changes {
+            self.map[pos] = after;
+        }
+        if event.moved {
+            self.robot = event.robot.step(event.direction);
+        }
+        self.applied += 1;
+        Some(event)
+    }
+
+    /// Steps backwards or forwards until the map shows the result of the first `position` events.
+    pub fn seek(&mut self, position: usize) {
+        while self.applied > position && self.step_back().is_some() {}
+        while self.applied < position && self.step_forward().is_some() {}
+    }
+
+    /// Every box the robot would push by moving in `direction`, without moving anything, or `None`
+    /// if a wall is in the way of the robot or any of those boxes.
+    pub fn dry_run(&self, direction: Direction) -> Option<Vec<Vec<Point>>> {
+        let mut boxes = Vec::new();
+        let mut seen = HashSet::from([self.robot]);
+        // Cells that move, and so need to check what they'd move into
+        let mut moving = vec![self.robot];
+        while let Some(pos) = moving.pop() {
+            let next = pos.step(direction);
+            if seen.contains(&next) {
+                continue;
+            }
+            match *self.map.get(next)? {
+                b'#' => return None,
+                c if is_box(c) => {
+                    let cells = self.box_at(next);
+                    seen.extend(cells.iter().copied());
+                    moving.extend(cells.iter().copied());
+                    boxes.push(cells);
+                }
+                _ => {}
+            }
+        }
+        Some(boxes)
+    }
+
+    /// Every cell of the box at `pos`: `O` is a box of its own, `[` and `]` with any `=` between
+    /// them make a wide box, and letters make a box of any shape out of a connected group of the
+    /// same letter other than `O`.
+    pub fn box_at(&self, pos: Point) -> Vec<Point> {
+        let mut cells = vec![pos];
+        let mut i = 0;
+        while i < cells.len() {
+            let cell = cells[i];
+            i += 1;
+            for next in self.map.neighbors4(cell) {
+                if !cells.contains(&next) && same_box(self.map[cell], self.map[next], next.x - cell.x) {
+                    cells.push(next);
+                }
+            }
+        }
+        cells.sort();
+        cells
+    }
+
+    /// Every box in the warehouse.
+    pub fn boxes(&self) -> Vec<Vec<Point>> {
+        let mut seen = HashSet::new();
+        let mut boxes = Vec::new();
+        for (pos, &c) in self.map.iter() {
+            if is_box(c) && !seen.contains(&pos) {
+                let cells = self.box_at(pos);
+                seen.extend(cells.iter().copied());
+                boxes.push(cells);
+            }
+        }
+        boxes
+    }
+
+    fn changes(&self, cells: &[Point], direction: Direction) -> Vec<(Point, u8, u8)> {
+        let moving = cells.iter().copied().collect::<HashSet<Point>>();
+        let mut touched = cells.iter()
+            .flat_map(|&pos| [pos, pos.step(direction)])
+            .collect::<Vec<Point>>();
+        touched.sort();
+        touched.dedup();
+        touched.into_iter()
+            .filter_map(|pos| {
+                let from = pos.step(direction.opposite());
+                let after = if moving.contains(&from) { self.map[from] } else { b'.' };
+                (self.map[pos] != after).then_some((pos, self.map[pos], after))
+            })
+            .collect()
+    }
+
+    /// Writes every frame, from before the first move to after the last recorded one, to a text
+    /// file, each frame headed by the move that led to it.
+    pub fn write_animation(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut replay = self.clone();
+        replay.seek(0);
+        let mut text = String::new();
+        writeln!(text, "Move 0/{}", self.events.len())?;
+        writeln!(text, "{}", replay.map)?;
+        while let Some(event) = replay.step_forward().cloned() {
+            writeln!(text, "Move {}/{}: {}", replay.applied, self.events.len(), event)?;
+            writeln!(text, "{}", replay.map)?;
+        }
+        fs::write(path.as_ref(), text)
+            .with_context(|| format!("Couldn't write {}", path.as_ref().display()))
+    }
+
+    /// Writes every frame as `frame_00000.ppm` and so on into `dir`, with `scale` pixels per tile
+    /// and the tiles that changed in each move highlighted.
+    pub fn write_frames(&self, dir: impl AsRef<Path>, scale: usize) -> Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let mut replay = self.clone();
+        replay.seek(0);
+        let mut changed = Vec::new();
+        loop {
+            let path = dir.join(format!("frame_{:05}.ppm", replay.applied));
+            fs::write(&path, ppm(&replay.map, &changed, scale))
+                .with_context(|| format!("Couldn't write {}", path.display()))?;
+            let Some(event) = replay.step_forward() else {
+                return Ok(());
+            };
+            changed = event.changes.iter().map(|&(pos, _, _)| pos).collect();
+        }
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.moved, self.pushed) {
+            (false, _) => write!(f, "{} blocked", self.direction),
+            (true, 0) => write!(f, "{}", self.direction),
+            (true, 1) => write!(f, "{} pushing 1 box", self.direction),
+            (true, n) => write!(f, "{} pushing {} boxes", self.direction, n),
+        }
+    }
+}
+
+/// The map as a binary PPM image.
+fn ppm(map: &Grid<u8>, highlight: &[Point], scale: usize) -> Vec<u8> {
+    map.to_ppm(scale, |pos, &c| {
+        let [r, g, b] = match c {
+            b'#' => [110, 110, 110],
+            b'[' => [196, 140, 64],
+            b']' => [166, 110, 44],
+            c if is_box(c) => [196, 140, 64],
+            b'@' => [220, 40, 40],
+            _ => [20, 20, 20],
+        };
+        if highlight.contains(&pos) {
+            [r / 2 + 127, g / 2 + 127, b / 2]
+        } else {
+            [r, g, b]
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+";
+
+    const WIDE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+";
+
+    #[test]
+    fn replays_the_small_example() {
+        let mut warehouse = Warehouse::new(SMALL.parse().unwrap()).unwrap();
+        let moves = Direction::parse_moves("<^^>>>vv<v>>v<<").unwrap();
+        warehouse.run(&moves);
+        assert_eq!(2028, warehouse.gps_sum());
+        assert_eq!(15, warehouse.events().len());
+
+        // The first move runs into a wall, the fourth pushes a box and the fifth two
+        assert!(!warehouse.events()[0].moved);
+        assert_eq!(1, warehouse.events()[3].pushed);
+        assert_eq!(2, warehouse.events()[4].pushed);
+
+        let end = warehouse.map().clone();
+        warehouse.seek(0);
+        assert_eq!(SMALL, warehouse.map().to_string());
+        assert_eq!(Point::new(2, 2), warehouse.robot());
+        warehouse.seek(15);
+        assert_eq!(&end, warehouse.map());
+        assert!(warehouse.step_forward().is_none());
+
+        // Pushing after stepping back forgets the moves that were stepped back over
+        warehouse.seek(10);
+        warehouse.push(Direction::Up);
+        assert_eq!(11, warehouse.events().len());
+    }
+
+    #[test]
+    fn pushes_wide_boxes() {
+        let mut warehouse = Warehouse::new(widen(&WIDE.parse().unwrap()).unwrap()).unwrap();
+        warehouse.run(&Direction::parse_moves("<vv<<^^<<^^").unwrap());
+        assert_eq!("\
+##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############
+", warehouse.map().to_string());
+
+        // Pushing up from under the left half of the lower box moves all three
+        warehouse.seek(5);
+        let event = warehouse.step_forward().unwrap();
+        assert_eq!((Direction::Up, 3), (event.direction, event.pushed));
+        warehouse.step_back();
+        warehouse.step_back();
+        assert_eq!(4, warehouse.position());
+    }
+
+    #[test]
+    fn pushes_triple_width_boxes() {
+        let mut warehouse = Warehouse::new("\
+##########
+#........#
+#...[=]..#
+#..[=]...#
+#....@...#
+##########
+".parse().unwrap()).unwrap();
+        assert_eq!(vec![Point::new(4, 2), Point::new(5, 2), Point::new(6, 2)], warehouse.box_at(Point::new(5, 2)));
+        assert_eq!(2, warehouse.dry_run(Direction::Up).unwrap().len());
+
+        assert_eq!(2, warehouse.push(Direction::Up).pushed);
+        assert_eq!("\
+##########
+#...[=]..#
+#..[=]...#
+#....@...#
+#........#
+##########
+", warehouse.map().to_string());
+        assert_eq!(104 + 203, warehouse.gps_sum());
+
+        // The top box hits the wall, so neither moves
+        assert_eq!(None, warehouse.dry_run(Direction::Up));
+        assert!(!warehouse.push(Direction::Up).moved);
+        assert_eq!(Point::new(5, 3), warehouse.robot());
+    }
+
+    #[test]
+    fn pushes_shaped_boxes() {
+        let mut warehouse = Warehouse::new("\
+#######
+#.....#
+#.AA..#
+#..AB.#
+#...B@#
+#######
+".parse().unwrap()).unwrap();
+        assert_eq!(2, warehouse.boxes().len());
+        // The robot pushes B, which pushes the L-shaped A
+        let boxes = warehouse.dry_run(Direction::Left).unwrap();
+        assert_eq!(vec![vec![Point::new(4, 3), Point::new(4, 4)], vec![Point::new(2, 2), Point::new(3, 2), Point::new(3, 3)]], boxes);
+        warehouse.push(Direction::Left);
+        assert_eq!("\
+#######
+#.....#
+#AA...#
+#.AB..#
+#..B@.#
+#######
+", warehouse.map().to_string());
+        assert_eq!(201 + 303, warehouse.gps_sum());
+
+        // Only A's top arm is against the wall, that still stops everything
+        assert_eq!(None, warehouse.dry_run(Direction::Left));
+        warehouse.step_back();
+        assert_eq!(Point::new(5, 4), warehouse.robot());
+    }
+
+    #[test]
+    fn exports_frames() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-warehouse-test-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        let mut warehouse = Warehouse::new(SMALL.parse()?)?;
+        warehouse.run(&Direction::parse_moves("<^^>")?);
+
+        warehouse.write_animation(dir.join("animation.txt"))?;
+        let animation = fs::read_to_string(dir.join("animation.txt"))?;
+        assert!(animation.starts_with(&format!("Move 0/4\n{}\n", SMALL)));
+        assert!(animation.contains("Move 1/4: < blocked\n"));
+        assert!(animation.contains("Move 4/4: > pushing 1 box\n"));
+
+        warehouse.write_frames(&dir, 2)?;
+        let frame = fs::read(dir.join("frame_00004.ppm"))?;
+        assert!(frame.starts_with(b"P6\n16 16\n255\n"));
+        assert_eq!("P6\n16 16\n255\n".len() + 16 * 16 * 3, frame.len());
+        assert!(!dir.join("frame_00005.ppm").exists());
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+use anyhow::*;
+use std::collections::HashSet;
+use std::fmt;
+use std::io::BufRead;
+use crate::*;
+use crate::search::Search;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "16";
+
+const TEST: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+const TEST2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: &'static str = DAY;
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse(DAY, reader)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<usize> {
+        part1(input, params.turn_cost)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<usize> {
+        part2(input, params.turn_cost)
+    }
+}
+
+examples! {
+    Day16;
+    part1_test: part1(TEST) == 7036;
+    part1_test2: part1(TEST2) == 11048;
+    part2_test: part2(TEST) == 45;
+    part2_test2: part2(TEST2) == 64;
+    part1_cheap_turns: part1(TEST, Params { turn_cost: 1, ..Params::default() }) == 38;
+}
+
+//region Part 1
+type Node = (Point, Direction);
+
+/// One step along a path through the maze.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Move {
+    Forward,
+    TurnLeft,
+    TurnRight,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Move::Forward => 'F',
+            Move::TurnLeft => 'L',
+            Move::TurnRight => 'R',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+/// The maze, which the reindeer goes through from S to E, starting off facing right.
+pub struct Maze<'a> {
+    map: &'a Grid<u8>,
+    start: Point,
+    end: Point,
+    turn_cost: usize,
+}
+
+impl<'a> Maze<'a> {
+    pub fn new(map: &'a Grid<u8>, turn_cost: usize) -> Result<Self> {
+        // Free turns would let the reindeer spin on the spot forever, with no end of best paths
+        ensure!(turn_cost > 0, "Turning has to cost something");
+        let start = map.find(&b'S').ok_or(Error::msg("No start found"))?;
+        let end = map.find(&b'E').ok_or(Error::msg("No end found"))?;
+        Ok(Maze { map, start, end, turn_cost })
+    }
+
+    fn moves(&self, &(position, dir): &Node) -> Vec<(Node, usize)> {
+        let mut result = Vec::with_capacity(3);
+
+        // Go straight
+        let new_pos = position.step(dir);
+        if self.map.get(new_pos).is_some_and(|&c| c != b'#') {
+            result.push(((new_pos, dir), 1));
+        }
+
+        // Turn left or right
+        result.push(((position, dir.turn_right()), self.turn_cost));
+        result.push(((position, dir.turn_left()), self.turn_cost));
+        result
+    }
+
+    /// The best paths from the start to the end, if there are any.
+    pub fn solve(&self) -> Option<BestPaths> {
+        let search = search::dijkstra(
+            (self.start, Direction::Right),
+            |node| self.moves(node),
+            |&(position, _)| position == self.end,
+        );
+        Some(BestPaths { cost: search.cost()?, search })
+    }
+}
+
+/// Every path through a [Maze] that has the lowest score.
+pub struct BestPaths {
+    cost: usize,
+    search: Search<Node>,
+}
+
+impl BestPaths {
+    pub fn cost(&self) -> usize {
+        self.cost
+    }
+
+    /// How many best paths there are, saturating at `u128::MAX`.
+    pub fn count(&self) -> u128 {
+        self.search.count_shortest_paths()
+    }
+
+    /// The tiles that are on at least one of the best paths.
+    pub fn tiles(&self) -> HashSet<Point> {
+        self.search.shortest_path_nodes()
+            .into_iter()
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Up to `limit` of the best paths, as the moves that make them up.
+    pub fn paths(&self, limit: usize) -> Vec<Vec<Move>> {
+        self.search.shortest_paths(limit)
+            .into_iter()
+            .map(|nodes| nodes.windows(2)
+                .map(|pair| match (pair[0], pair[1]) {
+                    ((from, _), (to, _)) if from != to => Move::Forward,
+                    ((_, from), (_, to)) if to == from.turn_left() => Move::TurnLeft,
+                    _ => Move::TurnRight,
+                })
+                .collect())
+            .collect()
+    }
+
+    /// The map with every tile on a best path marked with `O`.
+    pub fn overlay(&self, map: &Grid<u8>) -> Grid<u8> {
+        let mut overlay = map.clone();
+        for pos in self.tiles() {
+            overlay[pos] = b'O';
+        }
+        overlay
+    }
+}
+
+fn part1(map: &Grid<u8>, turn_cost: usize) -> Result<usize> {
+    let best = Maze::new(map, turn_cost)?.solve().ok_or(Error::msg("No path found"))?;
+    Ok(best.cost())
+}
+//endregion
+
+//region Part 2
+fn part2(map: &Grid<u8>, turn_cost: usize) -> Result<usize> {
+    let best = Maze::new(map, turn_cost)?.solve().ok_or(Error::msg("No path found"))?;
+    Ok(best.tiles().len())
+}
+//endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::{self, oracle, MazeOptions, Rng};
+
+    fn score(moves: &[Move], turn_cost: usize) -> usize {
+        moves.iter().map(|&m| if m == Move::Forward { 1 } else { turn_cost }).sum()
+    }
+
+    #[test]
+    fn lists_every_best_path() {
+        for (input, count) in [(TEST, 3), (TEST2, 2)] {
+            let map: Grid<u8> = input.parse().unwrap();
+            let best = Maze::new(&map, 1000).unwrap().solve().unwrap();
+            assert_eq!(count, best.count());
+            let paths = best.paths(usize::MAX);
+            assert_eq!(count as usize, paths.len());
+            assert!(paths.iter().all(|path| score(path, 1000) == best.cost()));
+            assert_eq!(1, best.paths(1).len());
+        }
+    }
+
+    #[test]
+    fn renders_the_best_tiles() {
+        let map: Grid<u8> = TEST2.parse().unwrap();
+        let overlay = Maze::new(&map, 1000).unwrap().solve().unwrap().overlay(&map);
+        assert_eq!(64, overlay.find_all(&b'O').count());
+        assert_eq!("#O#OOO..........#", overlay.to_string().lines().nth(15).unwrap());
+    }
+
+    #[test]
+    fn goes_either_way_round() {
+        let map: Grid<u8> = "\
+#######
+#.....#
+#S.#.E#
+#.....#
+#######
+".parse().unwrap();
+        for turn_cost in [1, 1000] {
+            let best = Maze::new(&map, turn_cost).unwrap().solve().unwrap();
+            // Round the top or the bottom of the wall, turning before or after the first step
+            assert_eq!(6 + 3 * turn_cost, best.cost());
+            assert_eq!(4, best.count());
+            let mut paths = best.paths(usize::MAX).iter()
+                .map(|path| path.iter().map(Move::to_string).collect::<String>())
+                .collect::<Vec<_>>();
+            paths.sort();
+            assert_eq!(vec!["FLFRFFFRF", "FRFLFFFLF", "LFRFFFFRF", "RFLFFFFLF"], paths);
+        }
+        assert!(Maze::new(&map, 0).is_err());
+    }
+
+    #[test]
+    fn agrees_with_bfs_on_random_mazes() {
+        check_random_mazes(400);
+    }
+
+    #[test]
+    #[ignore = "slow, run with --ignored"]
+    fn agrees_with_bfs_on_thousands_of_random_mazes() {
+        check_random_mazes(5000);
+    }
+
+    fn check_random_mazes(count: u64) {
+        let mut rng = Rng::new(16);
+        for seed in 0..count {
+            let options = MazeOptions { width: rng.between(5..=21), height: rng.between(5..=21), loops: rng.below(4) as f64 / 10.0, seed };
+            let map = maze::generate(&options).unwrap();
+            let (start, end) = (map.find(&b'S').unwrap(), map.find(&b'E').unwrap());
+
+            // With turns costing 1 every move does, so a plain BFS over where and which way finds the best score
+            let distances = oracle::distances((start, Direction::Right), |&(pos, dir)| {
+                let mut next = vec![(pos, dir.turn_left()), (pos, dir.turn_right())];
+                if map[pos.step(dir)] != b'#' {
+                    next.push((pos.step(dir), dir));
+                }
+                next
+            });
+            let best = Direction::ALL.iter().filter_map(|&dir| distances.get(&(end, dir))).min().copied();
+            assert_eq!(best, part1(&map, 1).ok(), "{:?}\n{}", options, map);
+
+            let steps = oracle::tile_distances(&map, start)[&end];
+            let best = Maze::new(&map, 1000).unwrap().solve().unwrap();
+            let paths = best.paths(10);
+            assert!(best.count() >= paths.len() as u128 && !paths.is_empty());
+            for path in paths {
+                assert_eq!(best.cost(), score(&path, 1000));
+                assert!(path.iter().filter(|&&m| m == Move::Forward).count() >= steps);
+            }
+            assert!(best.tiles().contains(&start) && best.tiles().contains(&end));
+        }
+    }
+}
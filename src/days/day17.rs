@@ -0,0 +1,88 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "17";
+
+const TEST: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+const TEST2: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+pub mod assembler;
+pub mod computer;
+pub mod quine;
+
+use computer::{Computer, Registers};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: &'static str = DAY;
+    /// The computer with its initial registers and the program loaded
+    type Input = Computer;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse(reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day17;
+    part1_test: part1(TEST) == "4,6,3,5,6,3,5,2,1,0".to_string();
+    part2_test2: part2(TEST2) == 117440;
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<Computer> {
+    let input = parse::read_all(reader)?;
+    let [registers, program] = parse::exact_sections(DAY, &input)?;
+    let ([a, b, c], [program_line]) = (registers.as_slice(), program.as_slice()) else {
+        bail!("Expected 3 registers and then the program");
+    };
+    let registers = Registers {
+        a: extract!(a, "Register A: {}" => usize)?,
+        b: extract!(b, "Register B: {}" => usize)?,
+        c: extract!(c, "Register C: {}" => usize)?,
+    };
+
+    let [program] = program_line.fields("Program: {}")?;
+    let program = program_line.parse_list(program, ",")?;
+
+    Ok(Computer::new(registers, program))
+}
+
+//region Part 1
+fn part1(computer: &Computer) -> Result<String> {
+    let mut computer = computer.clone();
+    computer.run()?;
+    Ok(computer.output_string())
+}
+//endregion
+
+//region Part 2
+fn part2(computer: &Computer) -> Result<usize> {
+    let quines = quine::find_quines(computer, quine::BRUTE_FORCE_LIMIT)?;
+    quines.values.first().copied().ok_or(Error::msg("No solution found."))
+}
+//endregion
@@ -0,0 +1,174 @@
+use anyhow::*;
+use std::collections::HashMap;
+use crate::parse::{self, Line};
+use super::computer::{Computer, Instruction, Opcode, Operand, Registers};
+use super::DAY;
+
+/// Assembles three-bit assembly into a [Computer], whose [Computer::to_input] is then a puzzle
+/// input:
+///
+/// ```text
+/// ; Comments start with ; or #
+/// a = 2024        # Registers that aren't set are 0
+/// loop:
+///     adv 3       # Combo operands are 0 to 3, a, b or c
+///     out a
+///     jnz loop    # Literal operands are 0 to 7, jnz can use a label too
+/// ```
+///
+/// `bxc` ignores its operand, which can be left out.
+pub fn assemble(source: &str) -> Result<Computer> {
+    let mut registers = [None; 3];
+    let mut labels: HashMap<&str, (usize, Line)> = HashMap::new();
+    let mut instructions: Vec<(Line, Opcode, Target)> = Vec::new();
+
+    for line in parse::lines(DAY, source) {
+        let code = line.text.split([';', '#']).next().unwrap_or_default().trim();
+        if code.is_empty() {
+            continue;
+        }
+
+        if let Some((register, value)) = code.split_once('=') {
+            let register = register.trim();
+            let index = match register {
+                "a" | "A" => 0,
+                "b" | "B" => 1,
+                "c" | "C" => 2,
+                _ => return Err(line.error_in(register, format!("Expected register a, b or c, not '{}'", register))),
+            };
+            if registers[index].is_some() {
+                return Err(line.error_in(register, format!("Register {} is already set", register)));
+            }
+            registers[index] = Some(line.parse_field::<usize>(value)?);
+            continue;
+        }
+
+        let mut code = code;
+        if let Some((label, rest)) = code.split_once(':') {
+            let label = label.trim();
+            if label.is_empty() || !label.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                return Err(line.error_in(label, format!("'{}' isn't a valid label", label)));
+            }
+            if let Some((_, previous)) = labels.insert(label, (instructions.len() * 2, line)) {
+                return Err(line.error_in(label, format!("Label '{}' is already on line {}", label, previous.number)));
+            }
+            code = rest.trim();
+            if code.is_empty() {
+                continue;
+            }
+        }
+
+        let mut words = code.split_whitespace();
+        let mnemonic = words.next().unwrap_or_default();
+        let opcode = Opcode::from_mnemonic(mnemonic)
+            .ok_or_else(|| line.error_in(mnemonic, format!("Unknown instruction '{}'", mnemonic)))?;
+        let operand = words.next();
+        if let Some(extra) = words.next() {
+            return Err(line.error_in(extra, "Instructions take one operand"));
+        }
+        let operand = match (opcode, operand) {
+            (Opcode::Bxc, None) => Target::Resolved(Operand::Literal(0)),
+            (_, None) => return Err(line.error_at(line.text.len(), format!("{} needs an operand", mnemonic))),
+            (Opcode::Jnz, Some(label)) if label.starts_with(|c: char| !c.is_ascii_digit()) => Target::Label(label),
+            (_, Some(operand)) => Target::Resolved(parse_operand(&line, opcode, operand)?),
+        };
+        instructions.push((line, opcode, operand));
+    }
+
+    let mut program = Vec::with_capacity(instructions.len() * 2);
+    for (line, opcode, operand) in instructions {
+        let operand = match operand {
+            Target::Resolved(operand) => operand,
+            Target::Label(label) => {
+                let &(address, _) = labels.get(label)
+                    .ok_or_else(|| line.error_in(label, format!("Unknown label '{}'", label)))?;
+                if address > 7 {
+                    return Err(line.error_in(label, format!("Label '{}' is at {}, jnz can only jump to 0 to 7", label, address)));
+                }
+                Operand::Literal(address)
+            }
+        };
+        program.extend(Instruction { opcode, operand }.encode());
+    }
+
+    let [a, b, c] = registers.map(|value| value.unwrap_or(0));
+    Ok(Computer::new(Registers { a, b, c }, program))
+}
+
+// A jump's label is only resolved once all labels are known
+enum Target<'a> {
+    Resolved(Operand),
+    Label(&'a str),
+}
+
+fn parse_operand(line: &Line, opcode: Opcode, operand: &str) -> Result<Operand> {
+    if opcode.takes_combo() {
+        match operand {
+            "a" | "A" => return Ok(Operand::A),
+            "b" | "B" => return Ok(Operand::B),
+            "c" | "C" => return Ok(Operand::C),
+            _ => {}
+        }
+    }
+    let limit = if opcode.takes_combo() { 3 } else { 7 };
+    match line.parse_field::<usize>(operand) {
+        std::result::Result::Ok(value) if value <= limit => Ok(Operand::Literal(value)),
+        _ if opcode.takes_combo() => Err(line.error_in(operand, format!("Expected 0 to 3, a, b or c, not '{}'", operand))),
+        _ => Err(line.error_in(operand, format!("Expected 0 to 7, not '{}'", operand))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseError;
+    use crate::solution::Solution;
+    use super::super::{computer, Day17, TEST2};
+
+    #[test]
+    fn assembles_the_example() {
+        let computer = assemble("\
+; The second example
+a = 2024
+loop: adv 3
+      out a   # prints the next 3 bits
+      jnz loop
+").unwrap();
+        assert_eq!(TEST2, computer.to_input());
+        assert_eq!(computer, Day17::parse(TEST2.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn round_trips_the_disassembly() {
+        let source = "\
+b = 1
+c = 7
+bst a
+bxl 7
+cdv b
+bxc
+out b
+adv 3
+jnz 0";
+        let computer = assemble(source).unwrap();
+        assert_eq!(vec![2, 4, 1, 7, 7, 5, 4, 0, 5, 5, 0, 3, 3, 0], computer.program());
+        assert_eq!(Registers { a: 0, b: 1, c: 7 }, computer.registers);
+        let disassembled = computer::disassemble(computer.program()).unwrap().iter()
+            .map(|(_, instruction)| instruction.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(source.lines().skip(2).collect::<Vec<_>>(), disassembled);
+    }
+
+    #[test]
+    fn points_at_mistakes() {
+        let error = |source: &str| -> ParseError { assemble(source).unwrap_err().downcast().unwrap() };
+        let at = |source: &str| { let e = error(source); (e.line, e.column) };
+        assert_eq!((2, 5), at("adv 3\nout 7"));
+        assert_eq!((1, 1), at("mul 3"));
+        assert_eq!((3, 5), at("adv 1\nout a\njnz end"));
+        assert_eq!((1, 5), at("bxl 8"));
+        assert_eq!((1, 1), at("d = 1\n"));
+        assert_eq!("Label 'end' is at 8, jnz can only jump to 0 to 7",
+                   error("adv 1\nadv 1\nadv 1\nadv 1\nend: out a\njnz end").message);
+    }
+}
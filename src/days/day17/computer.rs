@@ -0,0 +1,417 @@
+use itertools::Itertools;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// The three-bit computer of day 17: three registers, a program of 3-bit numbers read as opcode
+/// and operand pairs, and an output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Computer {
+    pub registers: Registers,
+    /// Instruction pointer, the index of the next opcode in the program
+    pub ip: usize,
+    program: Vec<usize>,
+    output: Vec<usize>,
+    breakpoints: BTreeSet<usize>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Registers {
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+}
+
+/// In the order of their opcodes, so that `opcode as usize` is the opcode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+/// An operand, with combo operands 4 to 6 resolved to the register they read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Literal(usize),
+    A,
+    B,
+    C,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: Operand,
+}
+
+/// Why a program can't run, with the position of the offending instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ComputerError {
+    InvalidOpcode { ip: usize, opcode: usize },
+    /// Combo operand 7 is reserved and doesn't appear in valid programs
+    ReservedComboOperand { ip: usize },
+    /// Operands are 3-bit numbers
+    InvalidOperand { ip: usize, operand: usize },
+}
+
+/// One executed instruction, as recorded by [Computer::step].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub ip: usize,
+    pub instruction: Instruction,
+    pub before: Registers,
+    pub after: Registers,
+    pub output: Option<usize>,
+}
+
+/// Why [Computer::run] returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    Breakpoint(usize),
+}
+
+impl Computer {
+    pub fn new(registers: Registers, program: Vec<usize>) -> Self {
+        Computer { registers, ip: 0, program, output: Vec::new(), breakpoints: BTreeSet::new() }
+    }
+
+    pub fn program(&self) -> &[usize] {
+        &self.program
+    }
+
+    pub fn output(&self) -> &[usize] {
+        &self.output
+    }
+
+    /// The output the way the puzzle wants it, eg. `4,6,3,5`.
+    pub fn output_string(&self) -> String {
+        self.output.iter().join(",")
+    }
+
+    /// The registers and the program in the puzzle's input format.
+    pub fn to_input(&self) -> String {
+        format!("Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+                self.registers.a, self.registers.b, self.registers.c, self.program.iter().join(","))
+    }
+
+    /// Back to the start of the program with the given registers and no output, keeping the
+    /// breakpoints.
+    pub fn reset(&mut self, registers: Registers) {
+        self.registers = registers;
+        self.ip = 0;
+        self.output.clear();
+    }
+
+    /// The computer halts when there's no instruction at the instruction pointer, including when
+    /// an opcode is missing its operand.
+    pub fn is_halted(&self) -> bool {
+        self.ip + 1 >= self.program.len()
+    }
+
+    /// The instruction at the instruction pointer, if there is one.
+    pub fn current(&self) -> Result<Option<Instruction>, ComputerError> {
+        if self.is_halted() {
+            return Ok(None);
+        }
+        Instruction::decode(self.ip, self.program[self.ip], self.program[self.ip + 1]).map(Some)
+    }
+
+    /// Executes one instruction, or returns `None` if the computer has halted.
+    pub fn step(&mut self) -> Result<Option<Step>, ComputerError> {
+        let Some(instruction) = self.current()? else {
+            return Ok(None);
+        };
+        let ip = self.ip;
+        let before = self.registers;
+        let r = &mut self.registers;
+        let value = instruction.operand.value(r);
+        let mut output = None;
+        self.ip += 2;
+
+        match instruction.opcode {
+            Opcode::Adv => r.a = shift(r.a, value),
+            Opcode::Bxl => r.b ^= value,
+            Opcode::Bst => r.b = value % 8,
+            Opcode::Jnz => {
+                if r.a != 0 {
+                    self.ip = value;
+                }
+            }
+            Opcode::Bxc => r.b ^= r.c,
+            Opcode::Out => output = Some(value % 8),
+            Opcode::Bdv => r.b = shift(r.a, value),
+            Opcode::Cdv => r.c = shift(r.a, value),
+        }
+        self.output.extend(output);
+
+        Ok(Some(Step { ip, instruction, before, after: self.registers, output }))
+    }
+
+    /// Runs until the program halts or reaches a breakpoint. A computer stopped at a breakpoint
+    /// runs on from there when this is called again. Programs that loop forever run forever.
+    pub fn run(&mut self) -> Result<Stop, ComputerError> {
+        self.run_with(|_| {})
+    }
+
+    /// [Computer::run], handing every executed step to `on_step`.
+    pub fn run_with(&mut self, mut on_step: impl FnMut(&Step)) -> Result<Stop, ComputerError> {
+        let mut first = true;
+        loop {
+            if !first && self.breakpoints.contains(&self.ip) && !self.is_halted() {
+                return Ok(Stop::Breakpoint(self.ip));
+            }
+            first = false;
+            match self.step()? {
+                Some(step) => on_step(&step),
+                None => return Ok(Stop::Halted),
+            }
+        }
+    }
+
+    /// Runs until the program halts or reaches a breakpoint, recording every step.
+    pub fn trace(&mut self) -> Result<Vec<Step>, ComputerError> {
+        let mut steps = Vec::new();
+        self.run_with(|step| steps.push(*step))?;
+        Ok(steps)
+    }
+
+    /// Makes [Computer::run] stop before executing the instruction at `ip`.
+    pub fn add_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.insert(ip);
+    }
+
+    pub fn remove_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.remove(&ip);
+    }
+}
+
+/// The adv/bdv/cdv division by `2^value`, which is 0 rather than an overflow for huge values.
+fn shift(a: usize, value: usize) -> usize {
+    u32::try_from(value).ok().and_then(|value| a.checked_shr(value)).unwrap_or(0)
+}
+
+/// Decodes the whole program.
+pub fn disassemble(program: &[usize]) -> Result<Vec<(usize, Instruction)>, ComputerError> {
+    (0..program.len().saturating_sub(1))
+        .step_by(2)
+        .map(|ip| Instruction::decode(ip, program[ip], program[ip + 1]).map(|instruction| (ip, instruction)))
+        .collect()
+}
+
+/// A readable listing of the program, one instruction per line with what it does, eg.
+/// `  0: bst a    b = a % 8`.
+pub fn listing(program: &[usize]) -> Result<String, ComputerError> {
+    let lines = disassemble(program)?.into_iter()
+        .map(|(ip, instruction)| format!("{:>3}: {:<8} {}", ip, instruction.to_string(), instruction.describe()))
+        .collect::<Vec<String>>();
+    Ok(lines.join("\n"))
+}
+
+impl Instruction {
+    /// Decodes the opcode and operand at `ip`.
+    pub fn decode(ip: usize, opcode: usize, operand: usize) -> Result<Self, ComputerError> {
+        let opcode = match opcode {
+            0 => Opcode::Adv,
+            1 => Opcode::Bxl,
+            2 => Opcode::Bst,
+            3 => Opcode::Jnz,
+            4 => Opcode::Bxc,
+            5 => Opcode::Out,
+            6 => Opcode::Bdv,
+            7 => Opcode::Cdv,
+            _ => return Err(ComputerError::InvalidOpcode { ip, opcode }),
+        };
+        if operand > 7 {
+            return Err(ComputerError::InvalidOperand { ip: ip + 1, operand });
+        }
+        let operand = if opcode.takes_combo() {
+            match operand {
+                0..=3 => Operand::Literal(operand),
+                4 => Operand::A,
+                5 => Operand::B,
+                6 => Operand::C,
+                _ => return Err(ComputerError::ReservedComboOperand { ip: ip + 1 }),
+            }
+        } else {
+            Operand::Literal(operand)
+        };
+        Ok(Instruction { opcode, operand })
+    }
+
+    /// The opcode and operand that [Instruction::decode] decodes to this.
+    pub fn encode(&self) -> [usize; 2] {
+        let operand = match self.operand {
+            Operand::Literal(value) => value,
+            Operand::A => 4,
+            Operand::B => 5,
+            Operand::C => 6,
+        };
+        [self.opcode as usize, operand]
+    }
+
+    /// What the instruction does, in pseudo code.
+    pub fn describe(&self) -> String {
+        let operand = self.operand;
+        match self.opcode {
+            Opcode::Adv => format!("a = a >> {}", operand),
+            Opcode::Bxl => format!("b = b ^ {}", operand),
+            Opcode::Bst => format!("b = {} % 8", operand),
+            Opcode::Jnz => format!("if a != 0 jump to {}", operand),
+            Opcode::Bxc => "b = b ^ c".to_string(),
+            Opcode::Out => format!("output {} % 8", operand),
+            Opcode::Bdv => format!("b = a >> {}", operand),
+            Opcode::Cdv => format!("c = a >> {}", operand),
+        }
+    }
+}
+
+impl Opcode {
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Opcode> {
+        [Opcode::Adv, Opcode::Bxl, Opcode::Bst, Opcode::Jnz, Opcode::Bxc, Opcode::Out, Opcode::Bdv, Opcode::Cdv]
+            .into_iter()
+            .find(|opcode| opcode.mnemonic().eq_ignore_ascii_case(mnemonic))
+    }
+
+    /// Whether the operand is a combo operand rather than a literal.
+    pub fn takes_combo(&self) -> bool {
+        matches!(self, Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv)
+    }
+}
+
+impl Operand {
+    pub fn value(&self, registers: &Registers) -> usize {
+        match self {
+            Operand::Literal(value) => *value,
+            Operand::A => registers.a,
+            Operand::B => registers.b,
+            Operand::C => registers.c,
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Literal(value) => write!(f, "{}", value),
+            Operand::A => write!(f, "a"),
+            Operand::B => write!(f, "b"),
+            Operand::C => write!(f, "c"),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.opcode {
+            // bxc reads its operand but ignores it
+            Opcode::Bxc => write!(f, "{}", self.opcode.mnemonic()),
+            _ => write!(f, "{} {}", self.opcode.mnemonic(), self.operand),
+        }
+    }
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a={} b={} c={}", self.a, self.b, self.c)
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:>3}: {:<8} {} -> {}", self.ip, self.instruction.to_string(), self.before, self.after)?;
+        if let Some(output) = self.output {
+            write!(f, " out {}", output)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ComputerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComputerError::InvalidOpcode { ip, opcode } => write!(f, "Invalid opcode {} at {}", opcode, ip),
+            ComputerError::ReservedComboOperand { ip } => write!(f, "Reserved combo operand 7 at {}", ip),
+            ComputerError::InvalidOperand { ip, operand } => write!(f, "Operand {} at {} isn't a 3-bit number", operand, ip),
+        }
+    }
+}
+
+impl std::error::Error for ComputerError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_the_example() {
+        let mut computer = Computer::new(Registers { a: 729, b: 0, c: 0 }, vec![0, 1, 5, 4, 3, 0]);
+        assert_eq!(Ok(Stop::Halted), computer.run());
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", computer.output_string());
+        assert_eq!(Ok(None), computer.step());
+
+        // From the puzzle's smaller examples
+        let mut computer = Computer::new(Registers { a: 0, b: 2024, c: 43690 }, vec![4, 0]);
+        computer.run().unwrap();
+        assert_eq!(44354, computer.registers.b);
+    }
+
+    #[test]
+    fn steps_and_stops_at_breakpoints() {
+        let mut computer = Computer::new(Registers { a: 729, b: 0, c: 0 }, vec![0, 1, 5, 4, 3, 0]);
+        let step = computer.step().unwrap().unwrap();
+        assert_eq!("  0: adv 1    a=729 b=0 c=0 -> a=364 b=0 c=0", step.to_string());
+
+        computer.add_breakpoint(4);
+        let trace = computer.trace().unwrap();
+        assert_eq!(vec![2], trace.iter().map(|step| step.ip).collect::<Vec<_>>());
+        assert_eq!(Some(4), trace[0].output);
+        assert_eq!(4, computer.ip);
+        assert_eq!(Ok(Stop::Breakpoint(4)), computer.run());
+
+        computer.remove_breakpoint(4);
+        assert_eq!(Ok(Stop::Halted), computer.run());
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", computer.output_string());
+    }
+
+    #[test]
+    fn disassembles() {
+        let program = [2, 4, 1, 7, 7, 5, 4, 1, 5, 5, 0, 3, 3, 0];
+        assert_eq!("  0: bst a    b = a % 8
+  2: bxl 7    b = b ^ 7
+  4: cdv b    c = a >> b
+  6: bxc      b = b ^ c
+  8: out b    output b % 8
+ 10: adv 3    a = a >> 3
+ 12: jnz 0    if a != 0 jump to 0", listing(&program).unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_programs() {
+        assert_eq!(Err(ComputerError::ReservedComboOperand { ip: 3 }), disassemble(&[1, 7, 5, 7]));
+        assert_eq!(Err(ComputerError::InvalidOpcode { ip: 2, opcode: 8 }), disassemble(&[1, 7, 8, 0]));
+        assert_eq!(Err(ComputerError::InvalidOperand { ip: 1, operand: 9 }), disassemble(&[1, 9]));
+
+        let mut computer = Computer::new(Registers::default(), vec![1, 7, 5, 7]);
+        assert!(computer.step().unwrap().is_some());
+        assert_eq!(Err(ComputerError::ReservedComboOperand { ip: 3 }), computer.step());
+    }
+}
@@ -0,0 +1,230 @@
+use super::computer::{disassemble, Computer, ComputerError, Opcode, Operand, Registers};
+
+/// Register A values below this are tried when the program doesn't have a shape that
+/// [reverse_search] can work with.
+pub const BRUTE_FORCE_LIMIT: usize = 1 << 24;
+
+/// What [analyse] makes of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Shape {
+    /// A single loop that outputs one value per iteration, worked out from A alone, and then
+    /// shifts `shift` bits off of A until it's 0.
+    Loop { shift: u32 },
+    /// Anything else, and why.
+    Other(String),
+}
+
+/// How [find_quines] found its values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// Built A from its top bits down, which finds every value that works.
+    Reverse { shift: u32 },
+    /// Tried every A below `limit`, so values from there on could be missing.
+    BruteForce { limit: usize },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Quines {
+    /// In increasing order
+    pub values: Vec<usize>,
+    pub strategy: Strategy,
+}
+
+/// Checks whether the program is a loop that [reverse_search] can work with: it ends in the only
+/// jump, `jnz 0`, outputs once and shifts A once per iteration, and writes B and C before reading
+/// them, so that every iteration depends on nothing but what's left of A.
+pub fn analyse(program: &[usize]) -> Result<Shape, ComputerError> {
+    let instructions = disassemble(program)?;
+    let other = |reason: &str| Ok(Shape::Other(reason.to_string()));
+
+    let Some((body, (_, last))) = instructions.split_last().map(|(last, body)| (body, last)) else {
+        return other("The program is empty");
+    };
+    if last.opcode != Opcode::Jnz || last.operand != Operand::Literal(0) {
+        return other("The program doesn't end with jnz 0");
+    }
+    if body.iter().any(|(_, instruction)| instruction.opcode == Opcode::Jnz) {
+        return other("The program jumps more than once");
+    }
+    if body.iter().filter(|(_, instruction)| instruction.opcode == Opcode::Out).count() != 1 {
+        return other("The loop doesn't output exactly once");
+    }
+
+    let shifts = body.iter()
+        .filter(|(_, instruction)| instruction.opcode == Opcode::Adv)
+        .map(|(_, instruction)| instruction.operand)
+        .collect::<Vec<Operand>>();
+    let shift = match shifts.as_slice() {
+        [Operand::Literal(shift @ 1..=3)] => *shift as u32,
+        _ => return other("The loop doesn't shift A by a constant exactly once"),
+    };
+    if shift as usize * program.len() > usize::BITS as usize {
+        return other("The program is too long for A to hold a quine");
+    }
+
+    let (mut b_written, mut c_written) = (false, false);
+    for (_, instruction) in body {
+        let (reads_b, reads_c) = match (instruction.opcode, instruction.operand) {
+            (Opcode::Bxl, _) => (true, false),
+            (Opcode::Bxc, _) => (true, true),
+            (_, Operand::B) => (true, false),
+            (_, Operand::C) => (false, true),
+            _ => (false, false),
+        };
+        if (reads_b && !b_written) || (reads_c && !c_written) {
+            return other("The loop reads B or C before writing them");
+        }
+        match instruction.opcode {
+            Opcode::Bst | Opcode::Bdv => b_written = true,
+            Opcode::Cdv => c_written = true,
+            _ => {}
+        }
+    }
+
+    Ok(Shape::Loop { shift })
+}
+
+/// Every value of register A that makes the program output itself, using [reverse_search] if
+/// [analyse] allows it and trying every A below `limit` otherwise.
+pub fn find_quines(computer: &Computer, limit: usize) -> Result<Quines, ComputerError> {
+    match analyse(computer.program())? {
+        Shape::Loop { shift } => Ok(Quines {
+            values: reverse_search(computer, shift)?,
+            strategy: Strategy::Reverse { shift },
+        }),
+        Shape::Other(_) => Ok(Quines { values: brute_force(computer, limit)?, strategy: Strategy::BruteForce { limit } }),
+    }
+}
+
+/// For a [Shape::Loop], the last output only depends on the top `shift` bits of A, the one before
+/// on the top `2 * shift` bits and so on. So A can be built from the top down, one chunk at a time,
+/// keeping the chunks that make the program output the end of itself.
+pub fn reverse_search(computer: &Computer, shift: u32) -> Result<Vec<usize>, ComputerError> {
+    let mut computer = computer.clone();
+    let mut values = Vec::new();
+    let len = computer.program().len();
+    extend(&mut computer, shift, 0, len, &mut values)?;
+    values.sort();
+    Ok(values)
+}
+
+// `a` makes the program output program[index..], find the chunks that make it output one more
+fn extend(computer: &mut Computer, shift: u32, a: usize, index: usize, values: &mut Vec<usize>) -> Result<(), ComputerError> {
+    if index == 0 {
+        values.push(a);
+        return Ok(());
+    }
+    let len = computer.program().len();
+    for chunk in 0..(1 << shift) {
+        let a = (a << shift) | chunk;
+        // Once A is 0 the loop ends, so the top chunk can't be, unless it's the only one
+        if a == 0 && len > 1 {
+            continue;
+        }
+        let target = computer.program()[index - 1..].to_vec();
+        if outputs(computer, a, &target, usize::MAX)? {
+            extend(computer, shift, a, index - 1, values)?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs the program with every A below `limit`. Runs that go on for too long, or that output
+/// something other than the program, are cut short.
+pub fn brute_force(computer: &Computer, limit: usize) -> Result<Vec<usize>, ComputerError> {
+    let mut computer = computer.clone();
+    let program = computer.program().to_vec();
+    let max_steps = 1000 * program.len().max(1);
+    let mut values = Vec::new();
+    for a in 0..limit {
+        if outputs(&mut computer, a, &program, max_steps)? {
+            values.push(a);
+        }
+    }
+    Ok(values)
+}
+
+// Whether the program outputs `target` with A set to `a`, giving up as soon as it outputs something
+// else or after `max_steps`
+fn outputs(computer: &mut Computer, a: usize, target: &[usize], max_steps: usize) -> Result<bool, ComputerError> {
+    computer.reset(Registers { a, ..computer.registers });
+    for _ in 0..max_steps {
+        if computer.step()?.is_none() {
+            return Ok(computer.output() == target);
+        }
+        if !target.starts_with(computer.output()) {
+            return Ok(false);
+        }
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::assembler::assemble;
+
+    fn computer(program: &[usize]) -> Computer {
+        Computer::new(Registers::default(), program.to_vec())
+    }
+
+    fn runs_as_quine(program: &[usize], a: usize) -> bool {
+        let mut computer = Computer::new(Registers { a, b: 0, c: 0 }, program.to_vec());
+        computer.run().unwrap();
+        computer.output() == program
+    }
+
+    #[test]
+    fn finds_every_quine_of_the_example() {
+        let program = [0, 3, 5, 4, 3, 0];
+        assert_eq!(Shape::Loop { shift: 3 }, analyse(&program).unwrap());
+        let quines = find_quines(&computer(&program), BRUTE_FORCE_LIMIT).unwrap();
+        assert_eq!(Strategy::Reverse { shift: 3 }, quines.strategy);
+        // The lowest 3 bits are shifted off before the first output
+        assert_eq!((117440..=117447).collect::<Vec<_>>(), quines.values);
+        assert_eq!(quines.values, brute_force(&computer(&program), 1 << 18).unwrap());
+    }
+
+    #[test]
+    fn agrees_with_brute_force_on_synthesized_programs() {
+        let mut found = 0;
+        for k in 0..8 {
+            for source in [
+                format!("bst a \n bxl {k} \n out b \n adv 1 \n jnz 0"),
+                format!("bst a \n bxl {k} \n out b \n adv 2 \n jnz 0"),
+                format!("adv 1 \n bst a \n bxl {k} \n cdv b \n bxc \n out b \n jnz 0"),
+                "adv 2 \n out a \n jnz 0".to_string(),
+                format!("bst a \n bxl {k} \n adv 3 \n out b \n jnz 0"),
+            ] {
+                let program = assemble(&source).unwrap().program().to_vec();
+                let Shape::Loop { shift } = analyse(&program).unwrap() else {
+                    panic!("{:?} should be a loop", program);
+                };
+                let values = reverse_search(&computer(&program), shift).unwrap();
+                let limit = 1 << (shift as usize * program.len()).min(14);
+                let expected = brute_force(&computer(&program), limit).unwrap();
+                assert_eq!(expected, values.iter().copied().filter(|&a| a < limit).collect::<Vec<_>>(), "{:?}", program);
+                assert!(values.iter().all(|&a| runs_as_quine(&program, a)), "{:?}", program);
+                found += values.len();
+            }
+        }
+        assert!(found > 0);
+    }
+
+    #[test]
+    fn falls_back_to_brute_force() {
+        // Outputs the initial B, so nothing works
+        let program = [5, 5, 0, 3, 3, 0];
+        assert!(matches!(analyse(&program).unwrap(), Shape::Other(_)));
+        // Two jumps
+        assert!(matches!(analyse(&[0, 3, 3, 4, 5, 4, 3, 0]).unwrap(), Shape::Other(_)));
+        // Never halts once A is nonzero
+        let program = [5, 4, 3, 0];
+        assert!(matches!(analyse(&program).unwrap(), Shape::Other(_)));
+
+        let quines = find_quines(&computer(&[5, 5, 0, 3, 3, 0]), 1 << 12).unwrap();
+        assert_eq!(Quines { values: vec![], strategy: Strategy::BruteForce { limit: 1 << 12 } }, quines);
+        // Runs forever for any A but 0, brute force has to give up on those
+        assert!(find_quines(&computer(&program), 1 << 12).unwrap().values.is_empty());
+    }
+}
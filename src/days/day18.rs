@@ -0,0 +1,264 @@
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+use crate::*;
+use crate::parse::ParseError;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "18";
+
+pub const TEST: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+pub mod timeline;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: &'static str = DAY;
+    /// Where each byte falls, in order
+    type Input = Vec<Point>;
+    type Answer1 = usize;
+    type Answer2 = Point;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse(reader)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<usize> {
+        part1(input, params.size, params.num_fallen)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Point> {
+        part2(input, params.size)
+    }
+}
+
+examples! {
+    Day18;
+    part1_test: part1(TEST, Params { size: 7, num_fallen: 12, ..Params::default() }) == 22;
+    part2_test: part2(TEST, Params { size: 7, num_fallen: 12, ..Params::default() }) == Point::new(6, 1);
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<Vec<Point>> {
+    let input = parse::read_all(reader)?;
+    parse::lines(DAY, &input)
+        .map(|line| {
+            let (x, y) = extract!(line, "{},{}" => i32, i32)?;
+            Ok(Point::new(x, y))
+        })
+        .collect()
+}
+
+//region Part 1
+// The corruptions grid is size x size, and we're going from the top left to the bottom right
+fn search(corruptions: &Grid<bool>) -> search::Search<Point> {
+    let goal = Point::new(corruptions.width() as i32 - 1, corruptions.height() as i32 - 1);
+
+    search::bfs(
+        Point::ORIGIN,
+        |&pos| corruptions.neighbors4(pos).filter(|&new_pos| !corruptions[new_pos]),
+        |&pos| pos == goal,
+    )
+}
+
+fn bfs(corruptions: &Grid<bool>) -> Option<usize> {
+    search(corruptions).cost()
+}
+
+// The bytes in `fallen` are the first lines of the input, in order
+fn corrupted(fallen: &[Point], size: usize) -> Result<Grid<bool>> {
+    let mut corruptions = Grid::new(size, size, false);
+    for (i, &pos) in fallen.iter().enumerate() {
+        *corruptions.get_mut(pos).ok_or_else(|| outside(i, pos, size))? = true;
+    }
+    Ok(corruptions)
+}
+
+// The byte on line `index + 1` of the input falls outside of the memory space
+fn outside(index: usize, pos: Point, size: usize) -> Error {
+    Error::new(ParseError {
+        day: DAY,
+        line: index + 1,
+        column: 1,
+        text: pos.to_string(),
+        message: format!("{} is outside of the {}x{} memory space", pos, size, size),
+    })
+}
+
+fn part1(input: &[Point], size: usize, num_fallen: usize) -> Result<usize> {
+    let corruptions = corrupted(&input[..num_fallen.min(input.len())], size)?;
+
+    // println!("{}", corruptions.map(|&c| if c { '#' } else { '.' }));
+
+    let answer = bfs(&corruptions);
+    answer.ok_or(Error::msg("No solution found"))
+}
+//endregion
+
+//region Part 2
+/// Index of the first byte that cuts off the exit. Once the way is blocked it stays blocked, so
+/// this is a binary search over how many bytes have fallen. Like part 1, it only needs the bytes
+/// up to the answer to be in the memory space.
+fn first_blocking(input: &[Point], size: usize) -> Result<Option<usize>> {
+    let memory = Grid::new(size, size, false);
+    let in_bounds = input.iter().position(|&pos| memory.get(pos).is_none()).unwrap_or(input.len());
+    let blocked = |last: usize| -> Result<bool> { Ok(bfs(&corrupted(&input[..=last], size)?).is_none()) };
+    // The first blocking byte is somewhere in low..high, or there isn't one if that's empty
+    let (mut low, mut high) = (0, in_bounds);
+    while low < high {
+        let mid = (low + high) / 2;
+        if blocked(mid)? {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    if low < in_bounds {
+        Ok(Some(low))
+    } else if in_bounds < input.len() {
+        // The way is still open when a byte falls outside of the memory space
+        Err(outside(in_bounds, input[in_bounds], size))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Length of the shortest path after each byte falls, `None` once the exit is cut off. Only
+/// searches again when a byte lands on the path found last time.
+pub fn path_lengths(input: &[Point], size: usize) -> Result<Vec<Option<usize>>> {
+    let mut corruptions = Grid::new(size, size, false);
+    let mut path = search(&corruptions).path().map(|path| path.into_iter().collect::<HashSet<Point>>());
+    let mut lengths = Vec::with_capacity(input.len());
+    for (i, &pos) in input.iter().enumerate() {
+        *corruptions.get_mut(pos).ok_or_else(|| outside(i, pos, size))? = true;
+        if path.as_ref().is_some_and(|path| path.contains(&pos)) {
+            path = search(&corruptions).path().map(|path| path.into_iter().collect());
+        }
+        lengths.push(path.as_ref().map(|path| path.len() - 1));
+    }
+    Ok(lengths)
+}
+
+fn part2(input: &[Point], size: usize) -> Result<Point> {
+    let blocking = first_blocking(input, size)?.ok_or(Error::msg("No solution found"))?;
+    Ok(input[blocking])
+}
+//endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::{self, oracle, MazeOptions, Rng};
+
+    // The inside of a generated maze, with its walls falling first and then random corridor tiles
+    fn falling_maze(size: usize, loops: f64, seed: u64) -> (Grid<u8>, Vec<Point>, usize) {
+        let map = maze::generate(&MazeOptions { width: size + 2, height: size + 2, loops, seed }).unwrap();
+        let inside = Grid::from_rows(map.rows().skip(1).take(size).map(|row| row[1..=size].to_vec()).collect()).unwrap();
+
+        let mut rng = Rng::new(seed);
+        let corners = [Point::ORIGIN, Point::new(size as i32 - 1, size as i32 - 1)];
+        let mut walls = inside.find_all(&b'#').collect::<Vec<Point>>();
+        let mut corridors = inside.positions()
+            .filter(|&pos| inside[pos] != b'#' && !corners.contains(&pos))
+            .collect::<Vec<Point>>();
+        rng.shuffle(&mut walls);
+        rng.shuffle(&mut corridors);
+        let num_walls = walls.len();
+        walls.extend(corridors);
+        (inside, walls, num_walls)
+    }
+
+    fn reachable(size: usize, bytes: &[Point]) -> Option<usize> {
+        let mut map = Grid::new(size, size, b'.');
+        for &pos in bytes {
+            map[pos] = b'#';
+        }
+        oracle::tile_distances(&map, Point::ORIGIN).get(&Point::new(size as i32 - 1, size as i32 - 1)).copied()
+    }
+
+    #[test]
+    fn tracks_the_path_length() {
+        let input = Day18::parse(TEST.as_bytes()).unwrap();
+        let lengths = path_lengths(&input, 7).unwrap();
+        assert_eq!(Some(22), lengths[11]);
+        assert_eq!(Some(20), first_blocking(&input, 7).unwrap());
+        assert!(lengths[..20].iter().all(Option::is_some) && lengths[20..].iter().all(Option::is_none));
+        assert!(lengths.windows(2).all(|pair| pair[1].is_none() || pair[0] <= pair[1]));
+        assert_eq!(None, first_blocking(&input[..20], 7).unwrap());
+    }
+
+    #[test]
+    fn rejects_bytes_outside_of_memory() {
+        let input = Day18::parse("5,4\n80,80\n".as_bytes()).unwrap();
+        for error in [
+            part1(&input, 71, 1024).unwrap_err(),
+            part2(&input, 71).unwrap_err(),
+            path_lengths(&input, 71).unwrap_err(),
+            timeline::frames(&input, 71, 0..3).unwrap_err(),
+        ] {
+            let error = error.downcast::<ParseError>().unwrap();
+            assert_eq!((2, "80,80"), (error.line, error.text.as_str()));
+        }
+        // Fine once everything up to the bad byte has been dealt with
+        assert_eq!(140, part1(&input, 71, 1).unwrap());
+
+        // Or if the way is cut off before it falls
+        let input = Day18::parse(format!("{}80,80\n", TEST).as_bytes()).unwrap();
+        assert_eq!(Point::new(6, 1), part2(&input, 7).unwrap());
+        assert_eq!(22, part1(&input, 7, 12).unwrap());
+    }
+
+    #[test]
+    fn agrees_with_bfs_on_random_mazes() {
+        check_random_mazes(200);
+    }
+
+    #[test]
+    #[ignore = "slow, run with --ignored"]
+    fn agrees_with_bfs_on_thousands_of_random_mazes() {
+        check_random_mazes(3000);
+    }
+
+    fn check_random_mazes(count: u64) {
+        let mut rng = Rng::new(18);
+        for seed in 0..count {
+            let size = rng.between(2..=6) * 2 + 1;
+            let (inside, bytes, num_walls) = falling_maze(size, rng.below(4) as f64 / 10.0, seed);
+            let end = Point::new(size as i32 - 1, size as i32 - 1);
+
+            let expected = oracle::tile_distances(&inside, Point::ORIGIN)[&end];
+            assert_eq!(expected, part1(&bytes, size, num_walls).unwrap(), "\n{}", inside);
+
+            let lengths = (0..bytes.len()).map(|fallen| reachable(size, &bytes[..=fallen])).collect::<Vec<_>>();
+            assert_eq!(lengths, path_lengths(&bytes, size).unwrap(), "\n{}", inside);
+            let blocking = lengths.iter().position(Option::is_none).unwrap();
+            assert_eq!(bytes[blocking], part2(&bytes, size).unwrap(), "\n{}", inside);
+        }
+    }
+}
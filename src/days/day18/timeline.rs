@@ -0,0 +1,214 @@
+use anyhow::*;
+use crossterm::style::Stylize;
+use crossterm::{cursor, terminal, ExecutableCommand};
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Write;
+use std::ops::Range;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+use crate::*;
+
+/// The memory space once some number of bytes have fallen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// How many bytes have fallen
+    pub fallen: usize,
+    pub corruptions: Grid<bool>,
+    /// The byte that fell last, if any have
+    pub last: Option<Point>,
+    /// A shortest path from the top left to the bottom right, unless the way is cut off
+    pub path: Option<Vec<Point>>,
+}
+
+impl Frame {
+    pub fn is_cut_off(&self) -> bool {
+        self.path.is_none()
+    }
+
+    fn caption(&self) -> String {
+        let last = self.last.map_or(String::new(), |pos| format!(", the last at {}", pos));
+        match &self.path {
+            Some(path) => format!("{} bytes fallen{}, shortest path {}", self.fallen, last, path.len() - 1),
+            None => format!("{} bytes fallen{}, no way through", self.fallen, last),
+        }
+    }
+
+    /// The grid as text, with `#` for corrupted cells, `@` for the byte that fell last and `O`
+    /// along the path.
+    pub fn render(&self) -> String {
+        self.render_with(|c| c.to_string())
+    }
+
+    /// [Frame::render] in colour, for a terminal that understands ANSI escapes.
+    pub fn render_ansi(&self) -> String {
+        self.render_with(|c| match c {
+            '@' => c.red().bold().to_string(),
+            '#' => c.grey().to_string(),
+            'O' => c.green().bold().to_string(),
+            _ => c.dark_grey().to_string(),
+        })
+    }
+
+    fn render_with(&self, style: impl Fn(char) -> String) -> String {
+        let path = self.path.iter().flatten().collect::<HashSet<&Point>>();
+        let mut text = String::new();
+        for pos in self.corruptions.positions() {
+            let c = if Some(pos) == self.last {
+                '@'
+            } else if self.corruptions[pos] {
+                '#'
+            } else if path.contains(&pos) {
+                'O'
+            } else {
+                '.'
+            };
+            text += &style(c);
+            if pos.x as usize == self.corruptions.width() - 1 {
+                text.push('\n');
+            }
+        }
+        writeln!(text, "{}", self.caption()).unwrap();
+        text
+    }
+
+    /// The grid as a PPM image with `scale` pixels per cell.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let path = self.path.iter().flatten().collect::<HashSet<&Point>>();
+        self.corruptions.to_ppm(scale, |pos, &corrupted| {
+            if Some(pos) == self.last {
+                [230, 40, 40]
+            } else if corrupted {
+                [110, 110, 110]
+            } else if path.contains(&pos) {
+                [60, 210, 90]
+            } else {
+                [20, 20, 20]
+            }
+        })
+    }
+}
+
+/// Parses a single number of fallen bytes (`1024`) or an inclusive range of them (`1024-3000`).
+pub fn parse_counts(spec: &str) -> Result<Range<usize>> {
+    let count = |s: &str| s.parse::<usize>().with_context(|| format!("Invalid count '{}'", spec));
+    let counts = match spec.split_once('-') {
+        Some((from, to)) => count(from)?..count(to)? + 1,
+        None => count(spec)?..count(spec)? + 1,
+    };
+    ensure!(!counts.is_empty(), "Empty count range '{}'", spec);
+    Ok(counts)
+}
+
+/// A frame for each number of fallen bytes in `counts`, searching again only when a byte lands on
+/// the path.
+pub fn frames(input: &[Point], size: usize, counts: Range<usize>) -> Result<Vec<Frame>> {
+    let mut corruptions = Grid::new(size, size, false);
+    let mut path = super::search(&corruptions).path();
+    let mut frames = Vec::with_capacity(counts.len());
+    for fallen in 0..counts.end.min(input.len() + 1) {
+        if fallen > 0 {
+            let pos = input[fallen - 1];
+            *corruptions.get_mut(pos).ok_or_else(|| super::outside(fallen - 1, pos, size))? = true;
+            if path.as_ref().is_some_and(|path| path.contains(&pos)) {
+                path = super::search(&corruptions).path();
+            }
+        }
+        if counts.contains(&fallen) {
+            let last = fallen.checked_sub(1).map(|i| input[i]);
+            frames.push(Frame { fallen, corruptions: corruptions.clone(), last, path: path.clone() });
+        }
+    }
+    Ok(frames)
+}
+
+/// Plays the frames in the terminal, `delay` apart, stopping on the frame that cuts the way off.
+pub fn play(frames: &[Frame], delay: Duration) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    for frame in frames {
+        stdout.execute(terminal::Clear(terminal::ClearType::All))?.execute(cursor::MoveTo(0, 0))?;
+        write!(stdout, "{}", frame.render_ansi())?;
+        stdout.flush()?;
+        if frame.is_cut_off() {
+            break;
+        }
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+/// Writes each frame to `dir` as `frame_01024.ppm` and so on, numbered by how many bytes fell.
+pub fn write_frames(frames: &[Frame], dir: impl AsRef<Path>, scale: usize) -> Result<()> {
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir)?;
+    for frame in frames {
+        let path = dir.join(format!("frame_{:05}.ppm", frame.fallen));
+        fs::write(&path, frame.to_ppm(scale))
+            .with_context(|| format!("Couldn't write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+    use super::super::{Day18, TEST};
+
+    #[test]
+    fn follows_the_bytes_to_the_cutoff() {
+        let input = Day18::parse(TEST.as_bytes()).unwrap();
+        let frames = frames(&input, 7, 11..22).unwrap();
+        assert_eq!(11, frames.len());
+        assert_eq!((11, 21), (frames[0].fallen, frames[10].fallen));
+
+        let twelve = &frames[1];
+        assert_eq!(Some(Point::new(5, 1)), twelve.last);
+        assert_eq!(22, twelve.path.as_ref().unwrap().len() - 1);
+        let text = twelve.render();
+        assert_eq!(12, text.matches(['#', '@']).count());
+        assert_eq!(23, text.matches('O').count());
+        assert!(text.ends_with("12 bytes fallen, the last at 5,1, shortest path 22\n"));
+
+        let first_cut_off = frames.iter().find(|frame| frame.is_cut_off()).unwrap();
+        assert_eq!((21, Some(Point::new(6, 1))), (first_cut_off.fallen, first_cut_off.last));
+        assert!(first_cut_off.render().ends_with("no way through\n"));
+        assert!(first_cut_off.render_ansi().contains("\x1b["));
+    }
+
+    #[test]
+    fn matches_a_frame_worked_out_from_scratch() {
+        let input = Day18::parse(TEST.as_bytes()).unwrap();
+        for frame in frames(&input, 7, 0..input.len() + 5).unwrap() {
+            let corruptions = super::super::corrupted(&input[..frame.fallen], 7).unwrap();
+            assert_eq!(corruptions, frame.corruptions);
+            let length = super::super::bfs(&corruptions);
+            assert_eq!(length, frame.path.as_ref().map(|path| path.len() - 1));
+        }
+    }
+
+    #[test]
+    fn parses_counts() {
+        assert_eq!(1024..1025, parse_counts("1024").unwrap());
+        assert_eq!(1024..3001, parse_counts("1024-3000").unwrap());
+        assert_eq!(5..6, parse_counts("5-5").unwrap());
+        assert!(parse_counts("3000-1024").is_err());
+        assert!(parse_counts("x").is_err());
+        assert!(parse_counts("5-").is_err());
+    }
+
+    #[test]
+    fn writes_images() -> Result<()> {
+        let input = Day18::parse(TEST.as_bytes()).unwrap();
+        let dir = std::env::temp_dir().join(format!("aoc-timeline-test-{}", std::process::id()));
+        write_frames(&frames(&input, 7, 20..22)?, &dir, 3)?;
+        let image = fs::read(dir.join("frame_00021.ppm"))?;
+        assert!(image.starts_with(b"P6\n21 21\n255\n"));
+        assert_eq!(b"P6\n21 21\n255\n".len() + 21 * 21 * 3, image.len());
+        assert!(dir.join("frame_00020.ppm").exists());
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "19";
+
+const TEST: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: &'static str = DAY;
+    /// Available towel patterns, then the designs to make from them
+    type Input = (Vec<String>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse(reader)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+examples! {
+    Day19;
+    part1_test: part1(TEST) == 6;
+    part2_test: part2(TEST) == 16;
+}
+
+fn parse<R: BufRead>(reader: R) -> Result<(Vec<String>, Vec<String>)> {
+    let input = parse::read_all(reader)?;
+    let [towels, questions] = parse::exact_sections(DAY, &input)?;
+    let towels = towels.iter()
+        .flat_map(|line| line.text.split(", "))
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+    let questions = questions.iter()
+        .map(|line| line.text.to_string())
+        .collect::<Vec<String>>();
+    Ok((towels, questions))
+}
+
+//region Part 1
+fn can_make(question: &str, towels: &[String], index: usize, memo: &mut Memo<usize, bool>) -> bool {
+    if index >= question.len() {
+        return true;
+    }
+    memo.get_or_compute(index, |memo| {
+        let cur_q = &question[index..];
+        towels.iter().any(|towel| {
+            cur_q.starts_with(towel.as_str()) && can_make(question, towels, index + towel.len(), memo)
+        })
+    })
+}
+
+fn part1((towels, questions): &(Vec<String>, Vec<String>)) -> Result<usize> {
+    let answer = questions.iter()
+        .filter(|q| {
+            can_make(q, towels, 0, &mut Memo::new())
+        })
+        .count();
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+fn num_ways(question: &str, towels: &[String], index: usize, memo: &mut Memo<usize, usize>) -> usize {
+    if index >= question.len() {
+        return 1;
+    }
+    memo.get_or_compute(index, |memo| {
+        let mut answer = 0usize;
+        for towel in towels {
+            let cur_q = &question[index..];
+            if cur_q.starts_with(towel.as_str()) {
+                answer += num_ways(question, towels, index + towel.len(), memo);
+            }
+        }
+        answer
+    })
+}
+
+fn part2((towels, questions): &(Vec<String>, Vec<String>)) -> Result<usize> {
+    let answer = questions.iter()
+        .map(|q| {
+            num_ways(q, towels, 0, &mut Memo::new())
+        })
+        .sum();
+    Ok(answer)
+}
+//endregion
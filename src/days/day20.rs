@@ -0,0 +1,147 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "20";
+
+const TEST: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: &'static str = DAY;
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse(DAY, reader)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<usize> {
+        part1(input, params.save_at_least)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<usize> {
+        part2(input, params.save_at_least)
+    }
+}
+
+examples! {
+    Day20;
+    part1_test: part1(TEST, Params { save_at_least: 1, ..Params::default() }) == 44;
+    part2_test: part2(TEST, Params { save_at_least: 50, ..Params::default() }) == 32 + 31 + 29 + 39 + 25 + 23 + 20 + 19 + 12 + 14 + 12 + 22 + 4 + 3;
+}
+
+//region Part 1
+// Flood fill along the track, so that we know the distance between `from` and everywhere
+fn distances_from(map: &Grid<u8>, from: Point) -> search::Search<Point> {
+    search::bfs(
+        from,
+        |&pos| map.neighbors4(pos).filter(|&new_pos| map[new_pos] != b'#'),
+        |_| false,
+    )
+}
+
+// Cheats go straight through walls, so one from a tile on the way from the start to a tile on the
+// way to the goal takes as long as the Manhattan distance between them, and each pair of tiles is
+// one cheat
+fn count_cheats(map: &Grid<u8>, cheat_duration: usize, save_at_least: usize) -> Result<usize> {
+    let start = map.find(&b'S').ok_or(Error::msg("No start found"))?;
+    let goal = map.find(&b'E').ok_or(Error::msg("No end found"))?;
+
+    let from_start = distances_from(map, start);
+    let mut to_goal = Grid::new(map.width(), map.height(), None);
+    for (&pos, &distance) in distances_from(map, goal).distances() {
+        to_goal[pos] = Some(distance);
+    }
+    let shortest_path = to_goal[start].ok_or(Error::msg("No path found"))?;
+
+    let reach = cheat_duration.min(map.width() + map.height()) as i32;
+    let mut count = 0;
+    for (&from, &before) in from_start.distances() {
+        for dy in -reach..=reach {
+            let reach_x = reach - dy.abs();
+            for dx in -reach_x..=reach_x {
+                let Some(&Some(after)) = to_goal.get(from + Point::new(dx, dy)) else {
+                    continue;
+                };
+                if before + (dx.abs() + dy.abs()) as usize + after + save_at_least <= shortest_path {
+                    count += 1;
+                }
+            }
+        }
+    }
+    Ok(count)
+}
+
+fn part1(map: &Grid<u8>, save_at_least: usize) -> Result<usize> {
+    count_cheats(map, 2, save_at_least)
+}
+//endregion
+
+//region Part 2
+fn part2(map: &Grid<u8>, save_at_least: usize) -> Result<usize> {
+    count_cheats(map, 20, save_at_least)
+}
+//endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::{self, oracle, MazeOptions, Rng};
+
+    // Every pair of tiles no more than `cheat_duration` apart is a cheat, and it saves whatever
+    // going the long way round between them costs
+    fn oracle_cheats(map: &Grid<u8>, cheat_duration: usize, save_at_least: usize) -> usize {
+        let from_start = oracle::tile_distances(map, map.find(&b'S').unwrap());
+        let to_goal = oracle::tile_distances(map, map.find(&b'E').unwrap());
+        let best = to_goal[&map.find(&b'S').unwrap()];
+        from_start.iter()
+            .flat_map(|(&from, &before)| to_goal.iter().map(move |(&to, &after)| (from, before, to, after)))
+            .filter(|&(from, before, to, after)| {
+                let cheat = from.manhattan(to) as usize;
+                cheat <= cheat_duration && before + cheat + after + save_at_least <= best
+            })
+            .count()
+    }
+
+    #[test]
+    fn agrees_with_bfs_on_random_mazes() {
+        check_random_mazes(1000);
+    }
+
+    #[test]
+    #[ignore = "slow, run with --ignored"]
+    fn agrees_with_bfs_on_thousands_of_random_mazes() {
+        check_random_mazes(10000);
+    }
+
+    fn check_random_mazes(count: u64) {
+        let mut rng = Rng::new(20);
+        for seed in 0..count {
+            let options = MazeOptions { width: rng.between(5..=15), height: rng.between(5..=15), loops: rng.below(3) as f64 / 10.0, seed };
+            let map = maze::generate(&options).unwrap();
+            let save_at_least = rng.between(1..=6);
+            assert_eq!(oracle_cheats(&map, 2, save_at_least), part1(&map, save_at_least).unwrap(), "{:?}\n{}", options, map);
+            assert_eq!(oracle_cheats(&map, 20, save_at_least), part2(&map, save_at_least).unwrap(), "{:?}\n{}", options, map);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+
+use crate::solution::Runnable;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+/// Every solved day, keyed by its `DAY` constant.
+pub static REGISTRY: LazyLock<BTreeMap<&'static str, &'static dyn Runnable>> = LazyLock::new(|| {
+    let days: &[&'static dyn Runnable] = &[
+        &day01::Day01,
+        &day02::Day02,
+        &day03::Day03,
+        &day04::Day04,
+        &day05::Day05,
+        &day06::Day06,
+        &day07::Day07,
+        &day08::Day08,
+        &day09::Day09,
+        &day10::Day10,
+        &day11::Day11,
+        &day12::Day12,
+        &day13::Day13,
+        &day14::Day14,
+        &day15::Day15,
+        &day16::Day16,
+        &day17::Day17,
+        &day18::Day18,
+        &day19::Day19,
+        &day20::Day20,
+    ];
+    days.iter().map(|&day| (day.day(), day)).collect()
+});
+
+/// The solution for `day`, eg. `"05"`, if it has been solved yet.
+pub fn get(day: &str) -> Option<&'static dyn Runnable> {
+    REGISTRY.get(day).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Params, Solution};
+    use crate::Point;
+
+    #[test]
+    fn registry_is_keyed_by_day() {
+        assert_eq!(20, REGISTRY.len());
+        assert!(REGISTRY.iter().all(|(&key, day)| key == day.day()));
+        assert_eq!(Some("18"), get("18").map(|day| day.day()));
+        assert!(get("26").is_none());
+    }
+
+    #[test]
+    fn runs_through_the_registry() {
+        let run = get("18").unwrap().run(&mut day18::TEST.as_bytes(), 2, &Params { size: 7, ..Params::default() }).unwrap();
+        assert_eq!("6,1", run.answer);
+        assert_eq!(Point::new(6, 1), day18::Day18::solve2(day18::TEST, &Params { size: 7, ..Params::default() }).unwrap());
+    }
+
+    #[test]
+    fn reports_where_parsing_failed() {
+        let err = get("18").unwrap().run(&mut "5,4\n4,x\n".as_bytes(), 1, &Params::default()).unwrap_err();
+        let err = err.downcast::<crate::parse::ParseError>().unwrap();
+        assert_eq!((2, 3), (err.line, err.column));
+        assert_eq!("4,x", err.text);
+    }
+}
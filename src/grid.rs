@@ -0,0 +1,246 @@
+use crate::parse::{self, Line};
+use crate::point::{Direction, Point};
+use anyhow::*;
+use std::fmt;
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A rectangular 2D map stored in row-major order, addressed by signed [Point]s so that stepping
+/// off the edge is a `None` rather than an underflow.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            ensure!(row.len() == width, "Row {} has width {}, expected {}", y, row.len(), width);
+            cells.extend(row);
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: Point) -> bool {
+        pos.x >= 0 && pos.x < self.width as i32 && pos.y >= 0 && pos.y < self.height as i32
+    }
+
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        if self.in_bounds(pos) {
+            Some(pos.y as usize * self.width + pos.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on 0, but an empty grid has no cells to chunk anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// In-bounds orthogonal neighbours, in the order right, down, left, up.
+    pub fn neighbors4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL.iter()
+            .map(move |&dir| pos.step(dir))
+            .filter(|&p| self.in_bounds(p))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours, clockwise starting from the right.
+    pub fn neighbors8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Point::NEIGHBORS8.iter()
+            .map(move |&offset| pos + offset)
+            .filter(|&p| self.in_bounds(p))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// The grid as a binary PPM image, `scale` by `scale` pixels per cell in the colour that
+    /// `color` picks for it.
+    pub fn to_ppm(&self, scale: usize, mut color: impl FnMut(Point, &T) -> [u8; 3]) -> Vec<u8> {
+        let mut image = format!("P6\n{} {}\n255\n", self.width * scale, self.height * scale).into_bytes();
+        image.reserve(self.cells.len() * scale * scale * 3);
+        for (y, row) in self.rows().enumerate() {
+            let colors = row.iter().enumerate()
+                .map(|(x, cell)| color(Point::new(x as i32, y as i32), cell))
+                .collect::<Vec<[u8; 3]>>();
+            for _ in 0..scale {
+                for color in &colors {
+                    for _ in 0..scale {
+                        image.extend_from_slice(color);
+                    }
+                }
+            }
+        }
+        image
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Position of the first cell (in row-major order) equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Point> {
+        self.iter().find(|(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+}
+
+impl Grid<u8> {
+    /// One row per line, with an error pointing at the first line that isn't as wide as the first.
+    pub fn from_lines<'a>(lines: impl IntoIterator<Item = Line<'a>>) -> Result<Self> {
+        let mut rows: Vec<Vec<u8>> = Vec::new();
+        for line in lines {
+            let width = rows.first().map_or(line.text.len(), |row| row.len());
+            if line.text.len() != width {
+                let message = format!("Expected a row {} wide like the first, not {}", width, line.text.len());
+                return Err(line.error_at(width.min(line.text.len()), message));
+            }
+            rows.push(line.text.as_bytes().to_vec());
+        }
+        Self::from_rows(rows)
+    }
+
+    /// Reads one row per line, stopping at the first blank line.
+    pub fn parse<R: BufRead>(day: &'static str, reader: R) -> Result<Self> {
+        let input = parse::read_all(reader)?;
+        Self::from_lines(parse::lines(day, &input).take_while(|line| !line.text.is_empty()))
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = Error;
+
+    // Without a day, errors only say which line
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse("", s.as_bytes())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("Position {} out of bounds for {}x{} grid", pos, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("Position {} out of bounds for {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for &cell in row {
+                write!(f, "{}", cell.into())?;
+            }
+            writeln!(f)?;
+        }
+        fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseError;
+
+    const MAP: &str = "\
+#.^
+.E#
+";
+
+    #[test]
+    fn parses_and_finds() {
+        let grid: Grid<u8> = MAP.parse().unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(Point::new(2, 0)), grid.find(&b'^'));
+        assert_eq!(vec![Point::new(0, 0), Point::new(2, 1)], grid.find_all(&b'#').collect::<Vec<_>>());
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(None, grid.get(Point::new(0, -1)));
+        assert_eq!(MAP, grid.to_string());
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0u8);
+        assert_eq!(vec![Point::new(1, 0), Point::new(0, 1)], grid.neighbors4(Point::ORIGIN).collect::<Vec<_>>());
+        assert_eq!(3, grid.neighbors8(Point::ORIGIN).count());
+        assert_eq!(8, grid.neighbors8(Point::new(1, 1)).count());
+    }
+
+    #[test]
+    fn draws_a_ppm() {
+        let grid: Grid<u8> = MAP.parse().unwrap();
+        let image = grid.to_ppm(2, |pos, &c| if c == b'#' { [255, 0, pos.x as u8] } else { [0; 3] });
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(header.len() + 6 * 4 * 3, image.len());
+        assert!(image.starts_with(header));
+        let pixels = &image[header.len()..];
+        // The top left cell is two pixels wide and two high
+        assert_eq!([255, 0, 0, 255, 0, 0, 0, 0, 0], pixels[..9]);
+        assert_eq!([255, 0, 0, 255, 0, 0, 0, 0, 0], pixels[18..27]);
+        // The # at 2,1 is on the third and fourth rows of pixels
+        assert_eq!([255, 0, 2], pixels[18 * 2 + 12..18 * 2 + 15]);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert!("##\n#\n".parse::<Grid<u8>>().is_err());
+        let error = Grid::parse("10", "...\n...\n....\n".as_bytes()).unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!(("10", 3, 4), (error.day, error.line, error.column));
+        assert_eq!("Day 10, line 3, column 4: Expected a row 3 wide like the first, not 4\n    ....\n       ^", error.to_string());
+    }
+}
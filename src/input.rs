@@ -0,0 +1,127 @@
+use anyhow::*;
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+/// Environment variable that overrides where puzzle inputs live.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Optional config file in the crate root, eg. `input_dir = "../aoc-inputs"`.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Finds puzzle inputs on disk. Each day has a main input, `09.txt`, and optionally named ones such
+/// as `09.alt.txt` for trying out other people's inputs.
+#[derive(Clone, Debug)]
+pub struct InputLoader {
+    dir: PathBuf,
+}
+
+impl InputLoader {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputLoader { dir: dir.into() }
+    }
+
+    /// Uses `$AOC_INPUT_DIR` if it's set, then `input_dir` from `aoc.toml`, and otherwise the
+    /// `input` folder in the crate root, so it doesn't matter where we're run from. Relative paths
+    /// in the config are relative to the crate root.
+    pub fn from_env() -> Result<Self> {
+        if let Some(dir) = std::env::var_os(INPUT_DIR_VAR) {
+            return Ok(InputLoader::new(dir));
+        }
+
+        let config_path = Path::new(CRATE_DIR).join(CONFIG_FILE);
+        if config_path.exists() {
+            let config: toml::Table = fs::read_to_string(&config_path)?.parse()
+                .with_context(|| format!("Invalid config file {}", config_path.display()))?;
+            if let Some(dir) = config.get("input_dir") {
+                let dir = dir.as_str()
+                    .ok_or_else(|| anyhow!("input_dir in {} should be a string", config_path.display()))?;
+                return Ok(InputLoader::new(Path::new(CRATE_DIR).join(dir)));
+            }
+        }
+
+        Ok(InputLoader::new(Path::new(CRATE_DIR).join("input")))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The file name without `.txt`, eg. `09` or `09.alt`, which is also how answers are keyed.
+    pub fn stem(day: &str, name: Option<&str>) -> String {
+        match name {
+            Some(name) => format!("{}.{}", day, name),
+            None => day.to_string(),
+        }
+    }
+
+    pub fn path(&self, day: &str, name: Option<&str>) -> PathBuf {
+        self.dir.join(format!("{}.txt", Self::stem(day, name)))
+    }
+
+    pub fn open(&self, day: &str, name: Option<&str>) -> Result<BufReader<File>> {
+        let path = self.path(day, name);
+        if !path.exists() {
+            let input = name.map_or("Input".to_string(), |name| format!("Input '{}'", name));
+            bail!(
+                "{} for day {} not found, expected it at {} (puzzle inputs are at https://adventofcode.com/2024/day/{}/input)",
+                input, day, path.display(), day.trim_start_matches('0'),
+            );
+        }
+        let file = File::open(&path).with_context(|| format!("Couldn't open {}", path.display()))?;
+        Ok(BufReader::new(file))
+    }
+
+    pub fn read_to_string(&self, day: &str, name: Option<&str>) -> Result<String> {
+        let mut input = String::new();
+        std::io::Read::read_to_string(&mut self.open(day, name)?, &mut input)?;
+        Ok(input)
+    }
+
+    /// Names of the extra inputs available for a day, not including the main one.
+    pub fn names(&self, day: &str) -> Result<Vec<String>> {
+        let prefix = format!("{}.", day);
+        let mut names = Vec::new();
+        for entry in fs::read_dir(&self.dir).with_context(|| format!("Couldn't read {}", self.dir.display()))? {
+            let file_name = entry?.file_name().to_string_lossy().into_owned();
+            if let Some(name) = file_name.strip_prefix(&prefix).and_then(|rest| rest.strip_suffix(".txt")) {
+                if !name.is_empty() {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_named_inputs() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("09.txt"), "12345\n")?;
+        fs::write(dir.join("09.alt.txt"), "2333133121414131402\n")?;
+        fs::write(dir.join("09.bob.txt"), "1\n")?;
+        fs::write(dir.join("10.txt"), "0\n")?;
+
+        let loader = InputLoader::new(&dir);
+        assert_eq!(dir.join("09.alt.txt"), loader.path("09", Some("alt")));
+        assert_eq!("12345\n", loader.read_to_string("09", None)?);
+        assert_eq!("1\n", loader.read_to_string("09", Some("bob"))?);
+        assert_eq!(vec!["alt", "bob"], loader.names("09")?);
+
+        let error = loader.open("11", None).unwrap_err().to_string();
+        assert!(error.contains("day 11") && error.contains(&dir.join("11.txt").display().to_string()), "{}", error);
+        let error = loader.open("09", Some("carol")).unwrap_err().to_string();
+        assert!(error.starts_with("Input 'carol' for day 09"), "{}", error);
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -1,3 +1,22 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod grid;
+pub mod input;
+pub mod maze;
+pub mod memo;
+pub mod parse;
+pub mod point;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+
+pub use grid::Grid;
+pub use memo::Memo;
+pub use point::{Direction, Point, Vec2};
+pub use solution::{Params, Runnable, Solution};
+
 pub fn start_day(day: &str) {
     println!("Advent of Code 2024 - Day {:0>2}", day);
 }
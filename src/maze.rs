@@ -0,0 +1,216 @@
+use anyhow::*;
+use crate::*;
+
+/// SplitMix64, which is small, random enough for mazes, and gives the same numbers everywhere for
+/// the same seed.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which mustn't be empty.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `range`, which mustn't be empty.
+    #[cfg(test)]
+    pub fn between(&mut self, range: std::ops::RangeInclusive<usize>) -> usize {
+        range.start() + self.below(range.end() - range.start() + 1)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    #[cfg(test)]
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// What [generate] makes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MazeOptions {
+    /// Size of the whole map, including the wall around the outside
+    pub width: usize,
+    pub height: usize,
+    /// How many of the walls between two corridors are knocked through, from 0 for exactly one
+    /// way between any two tiles to 1 for nothing left but pillars
+    pub loops: f64,
+    pub seed: u64,
+}
+
+impl Default for MazeOptions {
+    fn default() -> Self {
+        MazeOptions { width: 15, height: 15, loops: 0.1, seed: 0 }
+    }
+}
+
+/// A maze of `#` walls and `.` corridors, with `S` in the bottom left and `E` in the top right as
+/// in days 16 and 20. Corridors join up the tiles at odd coordinates, so with an even size the
+/// outside wall on the right or bottom is two thick, and every corridor is reachable from `S`.
+pub fn generate(options: &MazeOptions) -> Result<Grid<u8>> {
+    let &MazeOptions { width, height, loops, seed } = options;
+    ensure!(width >= 3 && height >= 3 && (width - 1) / 2 * ((height - 1) / 2) >= 2,
+            "A {}x{} maze doesn't have room for both S and E", width, height);
+    ensure!(width <= i32::MAX as usize && height <= i32::MAX as usize, "A {}x{} maze is too big", width, height);
+    ensure!((0.0..=1.0).contains(&loops), "Loops should be between 0 and 1, not {}", loops);
+
+    let mut rng = Rng::new(seed);
+    let mut map = Grid::new(width, height, b'#');
+    let is_cell = |pos: Point| pos.x % 2 == 1 && pos.y % 2 == 1 && pos.x < width as i32 - 1 && pos.y < height as i32 - 1;
+
+    // Depth first from the top left, carving through to a random cell that hasn't been reached yet
+    let mut stack = vec![Point::new(1, 1)];
+    map[Point::new(1, 1)] = b'.';
+    while let Some(&cell) = stack.last() {
+        let unreached = Direction::ALL.iter()
+            .map(|&dir| cell + dir.delta() * 2)
+            .filter(|&next| is_cell(next) && map[next] == b'#')
+            .collect::<Vec<Point>>();
+        if unreached.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let next = unreached[rng.below(unreached.len())];
+        map[Point::new((cell.x + next.x) / 2, (cell.y + next.y) / 2)] = b'.';
+        map[next] = b'.';
+        stack.push(next);
+    }
+
+    // Any wall left between two cells can go, which makes a loop
+    for pos in map.positions().collect::<Vec<Point>>() {
+        let between_cells = [Direction::Right, Direction::Down].iter().any(|&dir| {
+            is_cell(pos + dir.delta()) && is_cell(pos - dir.delta())
+        });
+        if between_cells && map[pos] == b'#' && rng.chance(loops) {
+            map[pos] = b'.';
+        }
+    }
+
+    // The last cell along an axis, the biggest odd coordinate inside the outside wall
+    let last = |size: usize| (size as i32 - 3) | 1;
+    map[Point::new(1, last(height))] = b'S';
+    map[Point::new(last(width), 1)] = b'E';
+    Ok(map)
+}
+
+/// Plain breadth first searches, as something for the tests to check the real searches against.
+#[cfg(test)]
+pub mod oracle {
+    use std::collections::{HashMap, VecDeque};
+    use std::hash::Hash;
+    use crate::*;
+
+    /// Distance to everything reachable from `start`, searching the whole graph.
+    pub fn distances<N, S>(start: N, mut successors: S) -> HashMap<N, usize>
+    where
+        N: Clone + Eq + Hash,
+        S: FnMut(&N) -> Vec<N>,
+    {
+        let mut distances = HashMap::from([(start.clone(), 0)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[&node];
+            for next in successors(&node) {
+                if !distances.contains_key(&next) {
+                    distances.insert(next.clone(), distance + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+
+    /// [distances] between the tiles of a map that aren't `#`.
+    pub fn tile_distances(map: &Grid<u8>, start: Point) -> HashMap<Point, usize> {
+        distances(start, |&pos| map.neighbors4(pos).filter(|&next| map[next] != b'#').collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(width: usize, height: usize, loops: f64, seed: u64) -> MazeOptions {
+        MazeOptions { width, height, loops, seed }
+    }
+
+    #[test]
+    fn makes_the_same_maze_from_the_same_seed() {
+        let maze = generate(&options(21, 11, 0.2, 7)).unwrap();
+        assert_eq!(maze, generate(&options(21, 11, 0.2, 7)).unwrap());
+        assert_ne!(maze, generate(&options(21, 11, 0.2, 8)).unwrap());
+        assert_eq!(Some(Point::new(1, 9)), maze.find(&b'S'));
+        assert_eq!(Some(Point::new(19, 1)), maze.find(&b'E'));
+    }
+
+    #[test]
+    fn connects_every_corridor() {
+        let mut rng = Rng::new(2024);
+        for seed in 0..500 {
+            let (width, height) = (rng.between(3..=30), rng.between(5..=30));
+            let loops = [0.0, 0.1, 0.5, 1.0][rng.below(4)];
+            let maze = generate(&options(width, height, loops, seed)).unwrap();
+            let open = maze.iter().filter(|&(_, &c)| c != b'#').count();
+            let start = maze.find(&b'S').unwrap();
+            assert_eq!(open, oracle::tile_distances(&maze, start).len(), "\n{}", maze);
+            assert!(maze.find(&b'E').is_some());
+
+            if loops == 0.0 {
+                // A tree has one edge fewer than it has tiles
+                let edges = maze.iter()
+                    .filter(|&(_, &c)| c != b'#')
+                    .flat_map(|(pos, _)| [pos + Point::new(1, 0), pos + Point::new(0, 1)])
+                    .filter(|&next| maze.get(next).is_some_and(|&c| c != b'#'))
+                    .count();
+                assert_eq!(open - 1, edges, "\n{}", maze);
+            }
+        }
+    }
+
+    #[test]
+    fn knocks_through_walls_for_loops() {
+        let count = |loops| generate(&options(31, 31, loops, 1)).unwrap().find_all(&b'#').count();
+        assert!(count(0.0) > count(0.3));
+        assert!(count(0.3) > count(1.0));
+        // Only the pillars between cells are left, and the outside wall
+        assert_eq!(31 * 4 - 4 + 14 * 14, count(1.0));
+    }
+
+    #[test]
+    fn rejects_mazes_without_room() {
+        assert!(generate(&options(3, 3, 0.0, 0)).is_err());
+        assert!(generate(&options(2, 9, 0.0, 0)).is_err());
+        assert!(generate(&options(9, 9, 1.5, 0)).is_err());
+        assert!(generate(&options(3, 5, 0.0, 0)).is_ok());
+    }
+
+    #[test]
+    fn shuffles_everything() {
+        let mut rng = Rng::new(1);
+        let mut items = (0..100).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!((0..100).collect::<Vec<_>>(), items);
+        items.sort();
+        assert_eq!((0..100).collect::<Vec<_>>(), items);
+        assert!((0..1000).all(|_| (3..=5).contains(&rng.between(3..=5))));
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// A cache for recursive solutions: [Memo::get_or_compute] hands the memo back to the computation,
+/// so it can recurse through the same cache.
+///
+/// Cycles aren't detected, a computation that ends up asking for its own key computes it again.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+/// How well a [Memo] did, eg. for a `// println!("{}", memo.stats())` while tuning a solution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo { cache: HashMap::new(), hits: 0, misses: 0 }
+    }
+
+    /// The cached value for `key`, or else the result of `compute`, which is then cached.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Forgets the cached values, but not the statistics.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats { hits: self.hits, misses: self.misses, entries: self.cache.len() }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoStats {
+    /// Fraction of lookups that were answered from the cache.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 { 0.0 } else { self.hits as f64 / lookups as f64 }
+    }
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} hits, {} misses ({:.1}% hit rate), {} entries",
+               self.hits, self.misses, self.hit_rate() * 100.0, self.entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_compute(n, |memo| fib(n - 1, memo) + fib(n - 2, memo))
+    }
+
+    #[test]
+    fn recurses_through_the_cache() {
+        let mut memo = Memo::new();
+        assert_eq!(12586269025, fib(50, &mut memo));
+        // Every n from 2 to 50 is computed once, and each but the last two is then hit once
+        assert_eq!(MemoStats { hits: 47, misses: 49, entries: 49 }, memo.stats());
+        assert_eq!(Some(&55), memo.get(&10));
+
+        assert_eq!(55, fib(10, &mut memo));
+        assert_eq!(48, memo.stats().hits);
+        assert_eq!("48 hits, 49 misses (49.5% hit rate), 49 entries", memo.stats().to_string());
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(49, memo.stats().misses);
+    }
+}
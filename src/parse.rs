@@ -0,0 +1,311 @@
+use anyhow::*;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// A problem with the puzzle input, pinned down to the day, line and column it happened at.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub day: &'static str,
+    /// 1-based, like an editor
+    pub line: usize,
+    /// 1-based, like an editor
+    pub column: usize,
+    /// The whole offending line
+    pub text: String,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.day.is_empty() {
+            write!(f, "Day {}, ", self.day)?;
+        }
+        writeln!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        writeln!(f, "    {}", self.text)?;
+        write!(f, "    {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of puzzle input that remembers where it came from, so that errors can point at it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line<'a> {
+    pub day: &'static str,
+    /// 1-based
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Numbers the lines of `input`. A trailing newline doesn't make an extra empty line.
+pub fn lines<'a>(day: &'static str, input: &'a str) -> impl Iterator<Item = Line<'a>> + 'a {
+    input.lines().enumerate().map(move |(i, text)| Line { day, number: i + 1, text })
+}
+
+/// Reads all of `reader`, for parsers that want to look at the input as a whole.
+pub fn read_all<R: BufRead>(mut reader: R) -> Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+impl<'a> Line<'a> {
+    /// An error pointing at byte `offset` into the line.
+    pub fn error_at(&self, offset: usize, message: impl Display) -> Error {
+        let offset = offset.min(self.text.len());
+        Error::new(ParseError {
+            day: self.day,
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            text: self.text.to_string(),
+            message: message.to_string(),
+        })
+    }
+
+    /// Where `field`, which must be a slice of this line, starts. Anything else counts as the start
+    /// of the line.
+    pub fn offset_of(&self, field: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let field_start = field.as_ptr() as usize;
+        if field_start >= start && field_start + field.len() <= start + self.text.len() {
+            field_start - start
+        } else {
+            0
+        }
+    }
+
+    /// An error pointing at `field`, a slice of this line.
+    pub fn error_in(&self, field: &str, message: impl Display) -> Error {
+        self.error_at(self.offset_of(field), message)
+    }
+
+    /// Parses `field`, a slice of this line, eg. one of the parts of a `split`.
+    pub fn parse_field<T>(&self, field: &'a str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        field.trim().parse::<T>()
+            .map_err(|e| self.error_in(field.trim(), format!("Couldn't parse '{}': {}", field.trim(), e)))
+    }
+
+    /// Parses the whole line.
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.parse_field(self.text)
+    }
+
+    pub fn split_once(&self, separator: &str) -> Result<(&'a str, &'a str)> {
+        self.text.split_once(separator)
+            .ok_or_else(|| self.error_at(self.text.len(), format!("Expected '{}'", separator)))
+    }
+
+    /// Parses the `separator` separated values of the whole line, eg. `75,47,61,53,29`.
+    pub fn list<T>(&self, separator: &str) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.parse_list(self.text, separator)
+    }
+
+    /// Parses the `separator` separated values of `field`, a slice of this line.
+    pub fn parse_list<T>(&self, field: &'a str, separator: &str) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        field.split(separator).map(|value| self.parse_field(value)).collect()
+    }
+
+    /// Parses every integer in the line, ignoring whatever is around them. A `-` right before
+    /// the digits makes the number negative, so `p=0,4 v=3,-3` gives `[0, 4, 3, -3]`.
+    pub fn ints<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        int_fields(self.text).map(|field| self.parse_field(field)).collect()
+    }
+
+    /// Matches the line against `pattern`, in which every `{}` captures the text up to whatever
+    /// follows it in the pattern, and returns the captured fields:
+    /// `"Button A: X+{}, Y+{}"` gives `["94", "34"]` for `Button A: X+94, Y+34`.
+    pub fn fields<const N: usize>(&self, pattern: &str) -> Result<[&'a str; N]> {
+        let mut literals = pattern.split("{}");
+        // split always yields at least one part
+        let prefix = literals.next().unwrap_or_default();
+        let mut rest = self.text.strip_prefix(prefix).ok_or_else(|| {
+            let matching = prefix.chars().zip(self.text.chars())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a.len_utf8())
+                .sum();
+            self.error_at(matching, format!("Expected '{}'", &prefix[matching..]))
+        })?;
+
+        let literals = literals.collect::<Vec<&str>>();
+        ensure!(literals.len() == N, "The pattern '{}' captures {} fields, not {}", pattern, literals.len(), N);
+        let mut fields = [""; N];
+        for (i, literal) in literals.iter().enumerate() {
+            let last = i == N - 1;
+            let (field, after) = if literal.is_empty() {
+                ensure!(last, "The pattern '{}' has two captures in a row", pattern);
+                (rest, "")
+            } else if last {
+                let field = rest.strip_suffix(literal)
+                    .ok_or_else(|| self.error_at(self.text.len(), format!("Expected the line to end with '{}'", literal)))?;
+                (field, "")
+            } else {
+                let at = rest.find(literal)
+                    .ok_or_else(|| self.error_in(rest, format!("Expected '{}' after this", literal)))?;
+                (&rest[..at], &rest[at + literal.len()..])
+            };
+            fields[i] = field;
+            rest = after;
+        }
+        if !rest.is_empty() {
+            return Err(self.error_in(rest, "Unexpected text at the end of the line"));
+        }
+        Ok(fields)
+    }
+}
+
+/// Numbers the lines of `input` and splits them into the sections between blank lines, eg. the
+/// rules and the updates of day 5.
+pub fn sections<'a>(day: &'static str, input: &'a str) -> Vec<Vec<Line<'a>>> {
+    let lines = lines(day, input).collect::<Vec<_>>();
+    lines.split(|line| line.text.trim().is_empty())
+        .filter(|section| !section.is_empty())
+        .map(|section| section.to_vec())
+        .collect()
+}
+
+/// [sections], for inputs that always have `N` of them.
+pub fn exact_sections<'a, const N: usize>(day: &'static str, input: &'a str) -> Result<[Vec<Line<'a>>; N]> {
+    let sections = sections(day, input);
+    let found = sections.len();
+    sections.try_into()
+        .map_err(|_| anyhow!("Day {}: expected {} sections separated by blank lines, found {}", day, N, found))
+}
+
+/// Parses every non-blank line of `input` as a `T`.
+pub fn parse_records<T>(day: &'static str, input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    lines(day, input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| line.text.parse::<T>().map_err(|e| line.error_at(0, e)))
+        .collect()
+}
+
+/// Every integer in `text`, for [FromStr] implementations. See [Line::ints].
+pub fn ints_in_line<T>(text: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    int_fields(text)
+        .map(|field| field.parse::<T>().map_err(|e| anyhow!("Couldn't parse '{}': {}", field, e)))
+        .collect()
+}
+
+fn int_fields(text: &str) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return None;
+        }
+        let start = if i > 0 && bytes[i - 1] == b'-' { i - 1 } else { i };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        Some(&text[start..i])
+    })
+}
+
+/// Parses a [Line] with a [Line::fields] pattern into a value per `{}`, each parsed as the
+/// matching type: `extract!(line, "p={},{} v={},{}" => i32, i32, i32, i32)`. A single type gives
+/// the value rather than a 1-tuple.
+#[macro_export]
+macro_rules! extract {
+    ($line:expr, $pattern:expr => $ty:ty) => {{
+        let line: &$crate::parse::Line = &$line;
+        line.fields::<1>($pattern).and_then(|[field]| line.parse_field::<$ty>(field))
+    }};
+    ($line:expr, $pattern:expr => $($ty:ty),+) => {{
+        let line: &$crate::parse::Line = &$line;
+        line.fields::<{ [$(stringify!($ty)),+].len() }>($pattern).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            // There are exactly as many fields as types
+            ::std::result::Result::Ok(($(line.parse_field::<$ty>(fields.next().unwrap_or_default())?,)+))
+        })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(e: Error) -> ParseError {
+        e.downcast().unwrap()
+    }
+
+    #[test]
+    fn points_at_the_problem() {
+        let input = "3   4\n4   x\n";
+        let lines = lines("01", input).collect::<Vec<_>>();
+        assert_eq!(2, lines.len());
+
+        let (a, b) = lines[1].split_once("   ").unwrap();
+        assert_eq!(4, lines[1].parse_field::<u32>(a).unwrap());
+        let error = parse_error(lines[1].parse_field::<u32>(b).unwrap_err());
+        assert_eq!(("01", 2, 5, "4   x"), (error.day, error.line, error.column, error.text.as_str()));
+        assert_eq!("Day 01, line 2, column 5: Couldn't parse 'x': invalid digit found in string\n    4   x\n        ^", error.to_string());
+
+        let error = parse_error(lines[0].split_once(",").unwrap_err());
+        assert_eq!((1, 6), (error.line, error.column));
+    }
+
+    #[test]
+    fn extracts_fields() {
+        let line = Line { day: "14", number: 3, text: "p=0,4 v=3,-3" };
+        assert_eq!((0, 4, 3, -3), extract!(line, "p={},{} v={},{}" => i32, i32, i32, i32).unwrap());
+        assert_eq!(["0,4", "3,-3"], line.fields("p={} v={}").unwrap());
+        assert_eq!(vec![0, 4, 3, -3], line.ints::<i32>().unwrap());
+
+        let error = parse_error(extract!(line, "p={},{} w={},{}" => i32, i32, i32, i32).unwrap_err());
+        assert_eq!((3, 5), (error.line, error.column));
+        let error = parse_error(extract!(line, "p={},{} v={},{}" => i32, i32, u32, u32).unwrap_err());
+        assert_eq!((3, 11), (error.line, error.column));
+        let error = parse_error(extract!(line, "p=0,5 v={}" => String).unwrap_err());
+        assert_eq!((3, 5), (error.line, error.column));
+
+        let line = Line { text: "Program: 0,1,5,4,3,0", ..line };
+        let [program] = line.fields("Program: {}").unwrap();
+        assert_eq!(vec![0, 1, 5, 4, 3, 0], line.parse_list::<u8>(program, ",").unwrap());
+        assert_eq!(10, parse_error(line.parse_list::<u8>(program, ";").unwrap_err()).column);
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "47|53\n97|13\n\n75,47,61\n97,61,53\n";
+        let [rules, updates] = exact_sections::<2>("05", input).unwrap();
+        assert_eq!(vec![47, 53], rules[0].list::<u32>("|").unwrap());
+        assert_eq!((5, "97,61,53"), (updates[1].number, updates[1].text));
+        assert!(exact_sections::<3>("05", input).is_err());
+
+        assert_eq!(vec![19, 10, 7], parse_records::<u32>("01", "19\n10\n\n7").unwrap());
+        assert_eq!(3, parse_error(parse_records::<u32>("01", "19\n\nx").unwrap_err()).line);
+        assert_eq!(vec![94, 34], ints_in_line::<u32>("Button A: X+94, Y+34").unwrap());
+    }
+}
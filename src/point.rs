@@ -0,0 +1,230 @@
+use anyhow::*;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset on a grid. (0, 0) is the top left, x grows to the right and y grows down.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Offsets read better as vectors, but they're the same thing as a point.
+pub type Vec2 = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    /// Offsets to the eight surrounding cells, clockwise starting from the right.
+    pub const NEIGHBORS8: [Vec2; 8] = [
+        Point::new(1, 0),
+        Point::new(1, 1),
+        Point::new(0, 1),
+        Point::new(-1, 1),
+        Point::new(-1, 0),
+        Point::new(-1, -1),
+        Point::new(0, -1),
+        Point::new(1, -1),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn step(self, dir: Direction) -> Point {
+        self + dir.delta()
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i32) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// One of the four orthogonal directions, declared in the order of "turning right".
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Right, Direction::Down, Direction::Left, Direction::Up];
+
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Up => Point::new(0, -1),
+        }
+    }
+
+    /// Position in [Direction::ALL], handy for indexing per-direction arrays.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Right | Direction::Left)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Up => '^',
+        }
+    }
+
+    /// Parses a sequence of `^v<>` moves, ignoring any whitespace (moves are often wrapped over
+    /// several lines).
+    pub fn parse_moves(s: &str) -> Result<Vec<Direction>> {
+        s.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(Direction::try_from)
+            .collect()
+    }
+
+    /// The moves as `^v<>`, wrapped every `width` moves like in the puzzle input.
+    pub fn format_moves(moves: &[Direction], width: usize) -> String {
+        moves.chunks(width.max(1))
+            .map(|line| line.iter().map(|d| d.to_char()).collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '>' => Ok(Direction::Right),
+            'v' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            '^' => Ok(Direction::Up),
+            _ => bail!("Unknown direction '{}'", c),
+        }
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(dir: Direction) -> Self {
+        dir.delta()
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::Down, Direction::Right.turn_right());
+        assert_eq!(Direction::Up, Direction::Right.turn_left());
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Down, Direction::Up.opposite());
+        for dir in Direction::ALL {
+            assert_eq!(dir, dir.turn_left().turn_right());
+            assert_eq!(Point::ORIGIN, dir.delta() + dir.opposite().delta());
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let p = Point::new(2, 3);
+        assert_eq!(Point::new(5, 3), p + Direction::Right.delta() * 3);
+        assert_eq!(Point::new(2, 2), p.step(Direction::Up));
+        assert_eq!(Point::new(-2, -3), -p);
+        assert_eq!(7, p.manhattan(Point::new(-1, -1)));
+    }
+
+    #[test]
+    fn parses_moves() {
+        let moves = Direction::parse_moves("<^\n>v").unwrap();
+        assert_eq!(vec![Direction::Left, Direction::Up, Direction::Right, Direction::Down], moves);
+        assert_eq!("<^>v", moves.iter().map(|d| d.to_char()).collect::<String>());
+        assert!(Direction::parse_moves("<x").is_err());
+        assert_eq!("<^>\nv\n", Direction::format_moves(&moves, 3));
+    }
+}
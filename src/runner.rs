@@ -0,0 +1,149 @@
+use crate::answers::{Answers, Verdict, ANSWERS_FILE};
+use crate::days::{self, REGISTRY};
+use crate::input::InputLoader;
+use crate::solution::{Params, Run, Runnable};
+use anyhow::*;
+use std::time::Duration;
+
+/// What `aoc run` should run: which days (all of them if empty) and optionally just one part.
+#[derive(Debug, Default, PartialEq)]
+pub struct RunOptions {
+    pub days: Vec<u32>,
+    pub part: Option<u32>,
+    /// Save answers that aren't in the answers file yet.
+    pub record: bool,
+    /// Run against a named input, eg. `alt` for `09.alt.txt`, rather than the main one.
+    pub input: Option<String>,
+}
+
+impl RunOptions {
+    /// Parses the arguments following `run`, eg. `5 12-16 --part 2 --input alt --record`.
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let mut options = RunOptions::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "--part" {
+                let part = args.next().ok_or(Error::msg("--part needs a value"))?;
+                let part = part.parse().with_context(|| format!("Invalid part '{}'", part))?;
+                ensure!(part == 1 || part == 2, "Part must be 1 or 2, not {}", part);
+                options.part = Some(part);
+            } else if arg == "--input" {
+                options.input = Some(args.next().ok_or(Error::msg("--input needs a name"))?.clone());
+            } else if arg == "--record" {
+                options.record = true;
+            } else {
+                options.days.extend(parse_days(arg)?);
+            }
+        }
+        Ok(options)
+    }
+
+    pub fn runs_part(&self, part: u32) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+/// Parses a single day (`5`) or an inclusive range of days (`12-16`).
+pub fn parse_days(spec: &str) -> Result<Vec<u32>> {
+    let parse_day = |s: &str| s.parse::<u32>().with_context(|| format!("Invalid day '{}'", spec));
+    let days = match spec.split_once('-') {
+        Some((from, to)) => parse_day(from)?..=parse_day(to)?,
+        None => parse_day(spec)?..=parse_day(spec)?,
+    };
+    ensure!(!days.is_empty(), "Empty day range '{}'", spec);
+    Ok(days.collect())
+}
+
+/// The registered solution for `day`, if it has been solved yet.
+pub fn find_day(day: u32) -> Option<&'static dyn Runnable> {
+    days::get(&format!("{:02}", day))
+}
+
+fn run_part(solution: &dyn Runnable, part: u32, loader: &InputLoader, name: Option<&str>) -> Result<Run> {
+    let mut input = loader.open(solution.day(), name)?;
+    solution.run(&mut input, part, &Params::default())
+}
+
+/// The solutions for the days in `options`, in the order they were asked for.
+pub fn select(options: &RunOptions) -> Result<Vec<&'static dyn Runnable>> {
+    if options.days.is_empty() {
+        return Ok(REGISTRY.values().copied().collect());
+    }
+    options.days.iter()
+        .map(|&day| find_day(day).ok_or_else(|| anyhow!("Day {} hasn't been solved yet", day)))
+        .collect()
+}
+
+/// Runs the selected days and prints one table row per part, checking each answer against the
+/// answers file. Fails if any answer doesn't match.
+pub fn run(options: &RunOptions) -> Result<()> {
+    let solutions = select(options)?;
+
+    let loader = InputLoader::from_env()?;
+    let answers_path = loader.dir().join(ANSWERS_FILE);
+    let mut answers = Answers::load(&answers_path)?;
+    let mut num_failed = 0;
+    let mut num_recorded = 0;
+
+    println!("Advent of Code 2024");
+    println!();
+    println!("{:>3}  {:>4}  {:<24}  {:>10}  Check", "Day", "Part", "Answer", "Time");
+    let mut total = Duration::ZERO;
+    for solution in solutions {
+        let stem = InputLoader::stem(solution.day(), options.input.as_deref());
+        if let Err(e) = solution.check() {
+            println!("{:>3}  {:>4}  examples failed: {}", stem, "-", e);
+            continue;
+        }
+        for part in [1, 2].into_iter().filter(|&part| options.runs_part(part)) {
+            match run_part(solution, part, &loader, options.input.as_deref()) {
+                Result::Ok(run) => {
+                    let elapsed = run.parse_time + run.solve_time;
+                    total += elapsed;
+
+                    let verdict = answers.verify(&stem, part, &run.answer);
+                    let mut check = verdict.to_string();
+                    match verdict {
+                        Verdict::Fail { .. } => num_failed += 1,
+                        Verdict::Unknown if options.record => {
+                            answers.record(&stem, part, &run.answer);
+                            num_recorded += 1;
+                            check += " (recorded)";
+                        }
+                        _ => {}
+                    }
+                    println!("{:>3}  {:>4}  {:<24}  {:>10.2?}  {}", stem, part, run.answer, elapsed, check);
+                }
+                Err(e) => println!("{:>3}  {:>4}  error: {:#}", stem, part, e),
+            }
+        }
+    }
+    println!("{:>3}  {:>4}  {:<24}  {:>10.2?}", "", "", "Total", total);
+
+    if num_recorded > 0 {
+        answers.save(&answers_path)?;
+        println!("Recorded {} new answer(s) in {}", num_recorded, answers_path.display());
+    }
+    ensure!(num_failed == 0, "{} answer(s) didn't match {}", num_failed, answers_path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_day_specs() {
+        assert_eq!(vec![5], parse_days("5").unwrap());
+        assert_eq!(vec![12, 13, 14, 15, 16], parse_days("12-16").unwrap());
+        assert!(parse_days("16-12").is_err());
+        assert!(parse_days("x").is_err());
+
+        let args = ["5", "12-13", "--part", "2", "--input", "alt", "--record"].map(String::from);
+        assert_eq!(
+            RunOptions { days: vec![5, 12, 13], part: Some(2), record: true, input: Some("alt".to_string()) },
+            RunOptions::parse(&args).unwrap(),
+        );
+        assert!(RunOptions::parse(&["--part".to_string(), "3".to_string()]).is_err());
+    }
+}
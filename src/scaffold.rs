@@ -0,0 +1,204 @@
+use crate::input::InputLoader;
+use anyhow::*;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+const TEMPLATE: &str = include_str!("../templates/dayNN.rs");
+
+/// A puzzle's worked example, with whichever answers are known so far.
+#[derive(Debug, Default, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+impl Example {
+    /// Reads an example from `path`, or from stdin if `path` is `-`.
+    pub fn read(path: &str) -> Result<Self> {
+        let mut text = String::new();
+        if path == "-" {
+            std::io::stdin().read_to_string(&mut text)?;
+        } else {
+            text = fs::read_to_string(path).with_context(|| format!("Couldn't read {}", path))?;
+        }
+        text.parse()
+    }
+}
+
+impl std::str::FromStr for Example {
+    type Err = Error;
+
+    /// The example input, optionally followed by a `---` line and the expected answers as
+    /// `part1 = 11` and `part2 = 31`.
+    fn from_str(s: &str) -> Result<Self> {
+        let (input, answers_text) = match s.split_once("\n---\n") {
+            Some((input, answers)) => (format!("{}\n", input), answers),
+            None => (s.to_string(), ""),
+        };
+
+        let mut answers = [None, None];
+        for line in answers_text.lines().filter(|l| !l.trim().is_empty()) {
+            let (key, value) = line.split_once('=').ok_or_else(|| anyhow!("Expected `partN = answer`, got '{}'", line))?;
+            let part = match key.trim() {
+                "part1" => 0,
+                "part2" => 1,
+                key => bail!("Unknown part '{}'", key),
+            };
+            answers[part] = Some(value.trim().trim_matches('"').to_string());
+        }
+        Ok(Example { input, answers })
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// Whether an answer fits the template's `usize` answers, or the part has to answer with a `String`
+fn is_usize(answer: &str) -> bool {
+    answer.parse::<usize>().is_ok()
+}
+
+/// An answer as a Rust expression: `usize` answers as they are, anything else as a `String`.
+fn answer_literal(answer: &str) -> String {
+    if is_usize(answer) {
+        answer.to_string()
+    } else {
+        format!("\"{}\".to_string()", escape(answer))
+    }
+}
+
+/// Switches a part of the template from answering with a `usize` to a `String`.
+fn answer_with_string(source: &str, part: usize) -> String {
+    let (stub, string_stub) = match part {
+        1 => ("fn part1(input: &[String]) -> Result<usize> {\n    // TODO: Solve Part 1 of the puzzle\n    let answer = input.len();",
+              "fn part1(input: &[String]) -> Result<String> {\n    // TODO: Solve Part 1 of the puzzle\n    let answer = input.len().to_string();"),
+        _ => ("fn part2(_input: &[String]) -> Result<usize> {\n    Ok(0)",
+              "fn part2(_input: &[String]) -> Result<String> {\n    Ok(String::new())"),
+    };
+    source
+        .replace(&format!("type Answer{} = usize;", part), &format!("type Answer{} = String;", part))
+        .replace(&format!("fn part{}(input: &Self::Input, _: &Params) -> Result<usize> {{", part),
+                 &format!("fn part{}(input: &Self::Input, _: &Params) -> Result<String> {{", part))
+        .replace(stub, string_stub)
+}
+
+/// The source of a new day's module, filled in from the template.
+pub fn render(day: &str, example: Option<&Example>) -> String {
+    let mut source = TEMPLATE
+        .replace("NN\"; // TODO: Fill the day", "NN\";")
+        .replace("NN", day);
+
+    if let Some(example) = example {
+        // A line continuation would swallow any leading whitespace in the example
+        let opening = if example.input.starts_with(char::is_whitespace) { "\"" } else { "\"\\\n" };
+        source = source.replace(
+            "\"\\\n<TEST-INPUT>\n\"; // TODO: Add the test input",
+            &format!("{}{}\";", opening, escape(&example.input)),
+        );
+
+        for (i, answer) in example.answers.iter().enumerate() {
+            if answer.as_deref().is_some_and(|answer| !is_usize(answer)) {
+                source = answer_with_string(&source, i + 1);
+            }
+        }
+        if example.answers.iter().any(Option::is_some) {
+            let lines = example.answers.iter().enumerate()
+                .map(|(i, answer)| match answer {
+                    Some(answer) => format!("    part{}_test: part{}(TEST) == {};", i + 1, i + 1, answer_literal(answer)),
+                    None => format!("    // part{}_test: part{}(TEST) == 0;", i + 1, i + 1),
+                })
+                .collect::<Vec<_>>();
+            let start = source.find("// TODO: Set the expected answers").unwrap();
+            let end = start + source[start..].find("}\n").unwrap() + 2;
+            source.replace_range(start..end, &format!("examples! {{\n    Day{};\n{}\n}}\n", day, lines.join("\n")));
+        }
+    }
+    source
+}
+
+/// Adds a day to the `pub mod` lines and the registry in `src/days/mod.rs`, keeping both sorted.
+pub fn register(mod_rs: &str, day: &str) -> Result<String> {
+    let module = format!("day{}", day);
+    let mod_line = format!("pub mod {};", module);
+    let entry_line = format!("        &{}::Day{},", module, day);
+    ensure!(!mod_rs.lines().any(|l| l == mod_line), "Day {} is already registered", day);
+
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+    for (new_line, prefix) in [(mod_line, "pub mod day"), (entry_line, "        &day")] {
+        let existing = lines.iter()
+            .enumerate()
+            .filter(|(_, l)| l.starts_with(prefix))
+            .map(|(i, l)| (i, l.clone()))
+            .collect::<Vec<_>>();
+        let (last, _) = *existing.last().ok_or_else(|| anyhow!("Couldn't find any '{}' lines", prefix.trim()))?;
+        let at = existing.iter()
+            .find(|(_, l)| *l > new_line)
+            .map_or(last + 1, |&(i, _)| i);
+        lines.insert(at, new_line);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Generates `src/days/dayNN.rs`, registers it with the runner and creates an empty `NN.txt` in the
+/// input directory to paste the puzzle input into.
+pub fn new_day(day: u32, example: Option<&Example>) -> Result<()> {
+    ensure!((1..=25).contains(&day), "There is no day {}", day);
+    let day = format!("{:02}", day);
+
+    let days_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/days");
+    let day_file = days_dir.join(format!("day{}.rs", day));
+    ensure!(!day_file.exists(), "{} already exists", day_file.display());
+    let mod_file = days_dir.join("mod.rs");
+    let mod_rs = fs::read_to_string(&mod_file).with_context(|| format!("Couldn't read {}", mod_file.display()))?;
+    let mod_rs = register(&mod_rs, &day)?;
+
+    fs::write(&day_file, render(&day, example))?;
+    fs::write(&mod_file, mod_rs)?;
+    println!("Created {} and registered it in {}", day_file.display(), mod_file.display());
+
+    let input_file = InputLoader::from_env()?.path(&day, None);
+    if !input_file.exists() {
+        fs::write(&input_file, "")?;
+        println!("Created {}, paste your puzzle input into it", input_file.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_examples() {
+        let example: Example = "3   4\n4 \"3\"\n---\npart1 = 11\npart2 = \"a,b\"\n".parse().unwrap();
+        assert_eq!("3   4\n4 \"3\"\n", example.input);
+        assert_eq!([Some("11".to_string()), Some("a,b".to_string())], example.answers);
+
+        let source = render("21", Some(&example));
+        assert!(source.contains("pub const DAY: &str = \"21\";\n"));
+        assert!(source.contains("const TEST: &str = \"\\\n3   4\n4 \\\"3\\\"\n\";"));
+        assert!(source.contains("    Day21;\n    part1_test: part1(TEST) == 11;\n    part2_test: part2(TEST) == \"a,b\".to_string();\n}"));
+        assert!(!source.contains("<TEST-INPUT>") && !source.contains("TODO: Set the expected answers"));
+        // Part 2 doesn't answer with a number, so it answers with a String instead
+        assert!(source.contains("    type Answer1 = usize;\n    type Answer2 = String;\n"));
+        assert!(source.contains("fn part2(input: &Self::Input, _: &Params) -> Result<String> {"));
+        assert!(source.contains("fn part2(_input: &[String]) -> Result<String> {\n    Ok(String::new())"));
+        assert!(source.contains("fn part1(input: &[String]) -> Result<usize> {"));
+
+        assert!("x\n---\npart3 = 1\n".parse::<Example>().is_err());
+    }
+
+    #[test]
+    fn registers_in_order() {
+        let mod_rs = "pub mod day01;\npub mod day03;\n\nfn f() {\n    [\n        &day01::Day01,\n        &day03::Day03,\n    ];\n}\n";
+        let registered = register(mod_rs, "02").unwrap();
+        assert_eq!(
+            "pub mod day01;\npub mod day02;\npub mod day03;\n\nfn f() {\n    [\n        &day01::Day01,\n        &day02::Day02,\n        &day03::Day03,\n    ];\n}\n",
+            registered,
+        );
+        assert!(register(&registered, "02").is_err());
+        assert!(register(mod_rs, "04").unwrap().contains("        &day03::Day03,\n        &day04::Day04,\n    ];"));
+    }
+}
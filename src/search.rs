@@ -0,0 +1,347 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// The outcome of a [dijkstra], [astar] or [bfs] run.
+///
+/// Searches stop as soon as every goal node at the optimal cost has been found, so only nodes
+/// that were settled before that point show up in [Search::distances]. A goal predicate that never
+/// matches turns the search into a full flood fill from the start.
+pub struct Search<N> {
+    start: N,
+    cost: Option<usize>,
+    goals: Vec<N>,
+    distances: HashMap<N, usize>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    /// Cost of the cheapest path to a goal, if one was reachable.
+    pub fn cost(&self) -> Option<usize> {
+        self.cost
+    }
+
+    /// Every goal node reached at the optimal cost.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// Shortest distance from the start to every settled node.
+    pub fn distances(&self) -> &HashMap<N, usize> {
+        &self.distances
+    }
+
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    /// Every node that precedes `node` on some shortest path to it.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], |preds| preds.as_slice())
+    }
+
+    /// One cheapest path from the start to the first goal found, inclusive of both ends.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.goals.first().and_then(|goal| self.path_to(goal))
+    }
+
+    /// One shortest path from the start to `node`, inclusive of both ends.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while path[path.len() - 1] != self.start {
+            let prev = self.predecessors(&path[path.len() - 1]).first()?;
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node that lies on at least one cheapest path to one of the goals.
+    pub fn shortest_path_nodes(&self) -> HashSet<N> {
+        let mut seen: HashSet<N> = HashSet::new();
+        let mut stack: Vec<N> = self.goals.clone();
+        while let Some(node) = stack.pop() {
+            if seen.contains(&node) {
+                continue;
+            }
+            stack.extend(self.predecessors(&node).iter().cloned());
+            seen.insert(node);
+        }
+        seen
+    }
+
+    /// How many distinct cheapest paths lead to the goals, saturating at `u128::MAX`. Only makes
+    /// sense if every step costs something, as otherwise paths can go round in circles.
+    pub fn count_shortest_paths(&self) -> u128 {
+        let mut nodes = self.shortest_path_nodes().into_iter().collect::<Vec<N>>();
+        // Predecessors are strictly closer to the start, so their counts are always done first
+        nodes.sort_by_key(|node| self.distances[node]);
+        let mut counts: HashMap<N, u128> = HashMap::with_capacity(nodes.len());
+        for node in nodes {
+            let count = if node == self.start {
+                1
+            } else {
+                self.predecessors(&node).iter()
+                    .fold(0u128, |count, pred| count.saturating_add(counts[pred]))
+            };
+            counts.insert(node, count);
+        }
+        self.goals.iter().fold(0, |count, goal| count.saturating_add(counts[goal]))
+    }
+
+    /// Up to `limit` of the cheapest paths to the goals, each from the start to a goal inclusive.
+    pub fn shortest_paths(&self, limit: usize) -> Vec<Vec<N>> {
+        let mut paths = Vec::new();
+        for goal in &self.goals {
+            self.collect_paths(goal, &mut Vec::new(), &mut paths, limit);
+        }
+        paths
+    }
+
+    // Walks back from `node` to the start, with `path` holding the nodes after it in reverse
+    fn collect_paths(&self, node: &N, path: &mut Vec<N>, paths: &mut Vec<Vec<N>>, limit: usize) {
+        if paths.len() >= limit {
+            return;
+        }
+        path.push(node.clone());
+        if *node == self.start {
+            paths.push(path.iter().rev().cloned().collect());
+        } else {
+            for pred in self.predecessors(node) {
+                self.collect_paths(pred, path, paths, limit);
+            }
+        }
+        path.pop();
+    }
+}
+
+struct Queued<N> {
+    priority: usize,
+    cost: usize,
+    node: N,
+}
+
+impl<N> Ord for Queued<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Min-heap, preferring the deeper node when A* priorities tie
+        other.priority.cmp(&self.priority)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+impl<N> PartialOrd for Queued<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<N> PartialEq for Queued<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<N> Eq for Queued<N> {}
+
+/// Cheapest path search where `successors` yields each neighbouring node with the cost of the
+/// step to it.
+pub fn dijkstra<N, S, I, G>(start: N, successors: S, is_goal: G) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// [dijkstra] guided by `heuristic`, which must never overestimate the remaining cost (and must
+/// be consistent, ie. never drop by more than the cost of a step).
+pub fn astar<N, S, I, H, G>(start: N, mut successors: S, mut heuristic: H, mut is_goal: G) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    H: FnMut(&N) -> usize,
+    G: FnMut(&N) -> bool,
+{
+    // Best cost found so far for nodes we've seen but not yet settled
+    let mut tentative: HashMap<N, usize> = HashMap::new();
+    let mut distances: HashMap<N, usize> = HashMap::new();
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut cost: Option<usize> = None;
+    let mut goals: Vec<N> = Vec::new();
+
+    let mut heap = BinaryHeap::new();
+    tentative.insert(start.clone(), 0);
+    heap.push(Queued { priority: heuristic(&start), cost: 0, node: start.clone() });
+    while let Some(Queued { priority, cost: node_cost, node }) = heap.pop() {
+        if cost.is_some_and(|best| priority > best) {
+            // Everything left is worse than the goals we've already found
+            break;
+        }
+        if distances.contains_key(&node) || tentative.get(&node).is_some_and(|&c| c < node_cost) {
+            continue;
+        }
+        distances.insert(node.clone(), node_cost);
+
+        if is_goal(&node) {
+            cost = Some(node_cost);
+            goals.push(node);
+            continue;
+        }
+
+        for (next, step_cost) in successors(&node) {
+            let next_cost = node_cost + step_cost;
+            match tentative.get(&next) {
+                Some(&c) if next_cost > c => {}
+                Some(&c) if next_cost == c => {
+                    // Another way of getting here that's just as good
+                    predecessors.entry(next).or_default().push(node.clone());
+                }
+                _ => {
+                    tentative.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![node.clone()]);
+                    heap.push(Queued { priority: next_cost + heuristic(&next), cost: next_cost, node: next });
+                }
+            }
+        }
+    }
+
+    Search { start, cost, goals, distances, predecessors }
+}
+
+/// Shortest path search where every step costs 1.
+pub fn bfs<N, S, I, G>(start: N, mut successors: S, mut is_goal: G) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    // Distances are final as soon as a node is discovered
+    let mut distances: HashMap<N, usize> = HashMap::new();
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut cost: Option<usize> = None;
+    let mut goals: Vec<N> = Vec::new();
+
+    let mut queue: VecDeque<N> = VecDeque::new();
+    distances.insert(start.clone(), 0);
+    queue.push_back(start.clone());
+    while let Some(node) = queue.pop_front() {
+        let node_cost = distances[&node];
+        if cost.is_some_and(|best| node_cost > best) {
+            break;
+        }
+        if is_goal(&node) {
+            cost = Some(node_cost);
+            goals.push(node);
+            continue;
+        }
+
+        for next in successors(&node) {
+            match distances.get(&next) {
+                Some(&c) if c == node_cost + 1 => {
+                    predecessors.entry(next).or_default().push(node.clone());
+                }
+                Some(_) => {}
+                None => {
+                    distances.insert(next.clone(), node_cost + 1);
+                    predecessors.insert(next.clone(), vec![node.clone()]);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    Search { start, cost, goals, distances, predecessors }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E
+";
+
+    fn open_neighbors(grid: &Grid<u8>, pos: &Point) -> Vec<Point> {
+        grid.neighbors4(*pos).filter(|&p| grid[p] != b'#').collect()
+    }
+
+    #[test]
+    fn searches_agree_on_cost() {
+        let grid: Grid<u8> = MAZE.parse().unwrap();
+        let start = grid.find(&b'S').unwrap();
+        let end = grid.find(&b'E').unwrap();
+
+        let by_bfs = bfs(start, |p| open_neighbors(&grid, p), |&p| p == end);
+        let by_dijkstra = dijkstra(start, |p| open_neighbors(&grid, p).into_iter().map(|n| (n, 1)), |&p| p == end);
+        let by_astar = astar(
+            start,
+            |p| open_neighbors(&grid, p).into_iter().map(|n| (n, 1)),
+            |p| p.manhattan(end) as usize,
+            |&p| p == end,
+        );
+
+        for search in [&by_bfs, &by_dijkstra, &by_astar] {
+            assert_eq!(Some(5), search.cost());
+            let path = search.path().unwrap();
+            assert_eq!(6, path.len());
+            assert_eq!((start, end), (path[0], path[5]));
+        }
+        // Three ways around the wall in the middle, which between them cover every open tile
+        assert_eq!(10, by_bfs.shortest_path_nodes().len());
+        assert_eq!(10, by_dijkstra.shortest_path_nodes().len());
+        for search in [&by_bfs, &by_dijkstra] {
+            assert_eq!(3, search.count_shortest_paths());
+            let paths = search.shortest_paths(usize::MAX);
+            assert_eq!(3, paths.len());
+            assert!(paths.iter().all(|path| path.len() == 6 && path[0] == start && path[5] == end));
+            assert_eq!(2, search.shortest_paths(2).len());
+        }
+    }
+
+    #[test]
+    fn floods_without_a_goal() {
+        let grid: Grid<u8> = MAZE.parse().unwrap();
+        let search = bfs(Point::ORIGIN, |p| open_neighbors(&grid, p), |_| false);
+        assert_eq!(None, search.cost());
+        assert_eq!(10, search.distances().len());
+        assert_eq!(Some(5), search.distance(&Point::new(3, 2)));
+    }
+
+    #[test]
+    fn weighted_edges() {
+        // 0 -> 1 -> 3 costs 2 + 2, 0 -> 2 -> 3 costs 1 + 3, 0 -> 3 directly costs 5
+        let edges: HashMap<u32, Vec<(u32, usize)>> = HashMap::from([
+            (0, vec![(1, 2), (2, 1), (3, 5)]),
+            (1, vec![(3, 2)]),
+            (2, vec![(3, 3)]),
+        ]);
+        let search = dijkstra(0, |n| edges.get(n).cloned().unwrap_or_default(), |&n| n == 3);
+        assert_eq!(Some(4), search.cost());
+        assert_eq!(HashSet::from([1, 2]), search.predecessors(&3).iter().copied().collect());
+        assert_eq!(HashSet::from([0, 1, 2, 3]), search.shortest_path_nodes());
+        assert_eq!(2, search.count_shortest_paths());
+        assert_eq!(vec![vec![0, 1, 3], vec![0, 2, 3]], {
+            let mut paths = search.shortest_paths(10);
+            paths.sort();
+            paths
+        });
+    }
+
+    #[test]
+    fn counts_paths_without_listing_them() {
+        // A ladder of 200 diamonds, each doubling the number of ways through
+        let search = bfs(0u32, |&n| match n % 3 {
+            0 => vec![n + 1, n + 2],
+            1 => vec![n + 2],
+            _ => vec![n + 1],
+        }, |&n| n == 600);
+        assert_eq!(Some(400), search.cost());
+        assert_eq!(u128::MAX, search.count_shortest_paths());
+    }
+}
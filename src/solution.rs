@@ -0,0 +1,161 @@
+use anyhow::*;
+use std::fmt::{Debug, Display};
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+/// Puzzle-specific knobs that differ between the worked examples and the real input. The defaults
+/// are the values for the real input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Params {
+    /// Size of the robots' room (day 14).
+    pub width: usize,
+    pub height: usize,
+    /// Cost of turning 90 degrees (day 16).
+    pub turn_cost: usize,
+    /// Size of the memory space (day 18).
+    pub size: usize,
+    /// How many bytes have fallen before part 1 looks for a path (day 18).
+    pub num_fallen: usize,
+    /// Smallest saving that makes a cheat worth counting (day 20).
+    pub save_at_least: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { width: 101, height: 103, turn_cost: 1000, size: 71, num_fallen: 1024, save_at_least: 100 }
+    }
+}
+
+/// A day's puzzle. The input is parsed once and then shared by both parts.
+pub trait Solution {
+    /// Two-digit day number, which is also the name of the input file.
+    const DAY: &'static str;
+
+    type Input;
+    type Answer1: Debug + Display + PartialEq;
+    type Answer2: Debug + Display + PartialEq;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Self::Answer1>;
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Self::Answer2>;
+
+    /// Parses `input` and solves part 1 of it, which is mostly what the examples need.
+    fn solve1(input: &str, params: &Params) -> Result<Self::Answer1> {
+        Self::part1(&Self::parse(input.as_bytes())?, params)
+    }
+
+    fn solve2(input: &str, params: &Params) -> Result<Self::Answer2> {
+        Self::part2(&Self::parse(input.as_bytes())?, params)
+    }
+}
+
+/// The answer to one part, with how long each phase took to get it.
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Object-safe view of a [Solution], so that days with different input and answer types can share
+/// a registry.
+pub trait Runnable: Sync {
+    fn day(&self) -> &'static str;
+
+    fn check(&self) -> Result<()>;
+
+    /// Parses `input` and solves `part` (1 or 2) of it.
+    fn run(&self, input: &mut dyn BufRead, part: u32, params: &Params) -> Result<Run>;
+}
+
+impl<S: Solution + Examples + Sync> Runnable for S {
+    fn day(&self) -> &'static str {
+        S::DAY
+    }
+
+    fn check(&self) -> Result<()> {
+        <S as Examples>::check()
+    }
+
+    fn run(&self, input: &mut dyn BufRead, part: u32, params: &Params) -> Result<Run> {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&parsed, params)?.to_string(),
+            2 => S::part2(&parsed, params)?.to_string(),
+            _ => bail!("There is no part {}", part),
+        };
+        Ok(Run { answer, parse_time, solve_time: start.elapsed() })
+    }
+}
+
+/// The worked examples from a puzzle's description. Implemented by [examples!], which also turns
+/// each example into a `#[test]`.
+pub trait Examples {
+    /// Runs every example, failing on the first wrong answer.
+    fn check() -> Result<()>;
+}
+
+#[doc(hidden)]
+pub fn check_example<A: Debug + PartialEq>(name: &str, expected: A, actual: Result<A>) -> Result<()> {
+    let actual = actual.with_context(|| format!("Example {} failed", name))?;
+    ensure!(expected == actual, "Example {}: expected {:?}, got {:?}", name, expected, actual);
+    Ok(())
+}
+
+/// Declares a day's examples as `name: part(INPUT[, params]) == expected;`, eg.
+///
+/// ```ignore
+/// examples! {
+///     Day18;
+///     part1_test: part1(TEST, Params { size: 7, num_fallen: 12, ..Params::default() }) == 22;
+/// }
+/// ```
+///
+/// Examples without params get [Params::default].
+#[macro_export]
+macro_rules! examples {
+    ($day:ty; $($name:ident: $part:ident($input:expr $(, $params:expr)?) == $expected:expr;)*) => {
+        impl $crate::solution::Examples for $day {
+            fn check() -> ::anyhow::Result<()> {
+                $(
+                    $crate::solution::check_example(
+                        stringify!($name),
+                        $expected,
+                        $crate::examples!(@solve $day, $part, $input, $crate::examples!(@params $($params)?)),
+                    )?;
+                )*
+                Ok(())
+            }
+        }
+
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            $(
+                #[test]
+                fn $name() -> ::anyhow::Result<()> {
+                    $crate::solution::check_example(
+                        stringify!($name),
+                        $expected,
+                        $crate::examples!(@solve $day, $part, $input, $crate::examples!(@params $($params)?)),
+                    )
+                }
+            )*
+        }
+    };
+    (@solve $day:ty, part1, $input:expr, $params:expr) => {
+        <$day as $crate::solution::Solution>::solve1($input, &$params)
+    };
+    (@solve $day:ty, part2, $input:expr, $params:expr) => {
+        <$day as $crate::solution::Solution>::solve2($input, &$params)
+    };
+    (@params) => { $crate::solution::Params::default() };
+    (@params $params:expr) => { $params };
+}
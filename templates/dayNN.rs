@@ -0,0 +1,54 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::*;
+use crate::solution::{Params, Solution};
+
+pub const DAY: &str = "NN"; // TODO: Fill the day
+
+const TEST: &str = "\
+<TEST-INPUT>
+"; // TODO: Add the test input
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: &'static str = DAY;
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = parse::read_all(reader)?;
+        // See crate::parse (sections, extract!, list, ints) for parsing that reports where bad input went wrong
+        Ok(parse::lines(DAY, &input).map(|line| line.text.to_string()).collect())
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> Result<usize> {
+        part2(input)
+    }
+}
+
+// TODO: Set the expected answers for the test input
+examples! {
+    DayNN;
+    part1_test: part1(TEST) == 0;
+    // part2_test: part2(TEST) == 0;
+}
+
+//region Part 1
+fn part1(input: &[String]) -> Result<usize> {
+    // TODO: Solve Part 1 of the puzzle
+    let answer = input.len();
+    Ok(answer)
+}
+//endregion
+
+//region Part 2
+fn part2(_input: &[String]) -> Result<usize> {
+    Ok(0)
+}
+//endregion